@@ -4,8 +4,20 @@ pub enum BoundType {
     Exclusive,
 }
 
+use num::Zero;
 use std::cmp::Ordering;
 
+impl BoundType {
+    /// The type of a bound computed from two others: exclusive if either of them is.
+    pub(crate) fn combine(&self, other: &BoundType) -> BoundType {
+        if *self == BoundType::Exclusive || *other == BoundType::Exclusive {
+            BoundType::Exclusive
+        } else {
+            BoundType::Inclusive
+        }
+    }
+}
+
 use std::ops::Add;
 use std::ops::Mul;
 use std::ops::Neg;
@@ -16,7 +28,7 @@ pub struct Bound<T> {
     pub value: T,
 }
 
-impl<T: Ord> Bound<T> {
+impl<T: PartialOrd> Bound<T> {
     pub fn upper_bound_max(a: Self, b: Self) -> Self {
         if a.is_upper_bound_max(&b) {
             a
@@ -26,7 +38,7 @@ impl<T: Ord> Bound<T> {
     }
 
     pub fn is_upper_bound_max(&self, other: &Self) -> bool {
-        match self.value.cmp(&other.value) {
+        match compare(&self.value, &other.value) {
             Ordering::Greater => true,
             Ordering::Less => false,
             Ordering::Equal => self.bound_type == BoundType::Inclusive,
@@ -41,12 +53,44 @@ impl<T: Ord> Bound<T> {
     }
 
     pub fn is_lower_bound_min(&self, other: &Self) -> bool {
-        match self.value.cmp(&other.value) {
+        match compare(&self.value, &other.value) {
             Ordering::Greater => false,
             Ordering::Less => true,
             Ordering::Equal => self.bound_type == BoundType::Inclusive,
         }
     }
+
+    pub fn upper_bound_min(a: Self, b: Self) -> Self {
+        if a.is_upper_bound_min(&b) {
+            a
+        } else {
+            b
+        }
+    }
+
+    pub fn is_upper_bound_min(&self, other: &Self) -> bool {
+        match compare(&self.value, &other.value) {
+            Ordering::Greater => false,
+            Ordering::Less => true,
+            Ordering::Equal => self.bound_type == BoundType::Exclusive,
+        }
+    }
+
+    pub fn lower_bound_max(a: Self, b: Self) -> Self {
+        if a.is_lower_bound_max(&b) {
+            a
+        } else {
+            b
+        }
+    }
+
+    pub fn is_lower_bound_max(&self, other: &Self) -> bool {
+        match compare(&self.value, &other.value) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => self.bound_type == BoundType::Exclusive,
+        }
+    }
 }
 
 impl<T> Bound<T> {
@@ -67,22 +111,140 @@ impl<T> Bound<T> {
         Bound::exclusive(self.value)
     }
 
-    pub fn combine<F: FnOnce(T, T) -> T>(self, other: Self, func: F) -> Self {
-        let bound_type = if self.bound_type == BoundType::Exclusive
-            || other.bound_type == BoundType::Exclusive
-        {
-            BoundType::Exclusive
-        } else {
-            BoundType::Inclusive
-        };
+    /// Applies `func` to the value, keeping the same bound type.
+    pub fn map<U, F: FnOnce(T) -> U>(self, func: F) -> Bound<U> {
+        Bound {
+            bound_type: self.bound_type,
+            value: func(self.value),
+        }
+    }
 
+    /// Switches between inclusive and exclusive, keeping the same value.
+    pub fn flip(self) -> Bound<T> {
+        match self.bound_type {
+            BoundType::Inclusive => Bound::exclusive(self.value),
+            BoundType::Exclusive => Bound::inclusive(self.value),
+        }
+    }
+
+    pub fn as_ref(&self) -> Bound<&T> {
         Bound {
-            bound_type,
+            bound_type: self.bound_type.clone(),
+            value: &self.value,
+        }
+    }
+
+    pub fn combine<U, F: FnOnce(T, T) -> U>(self, other: Self, func: F) -> Bound<U> {
+        Bound {
+            bound_type: self.bound_type.combine(&other.bound_type),
             value: func(self.value, other.value),
         }
     }
 }
 
+/// Orders two values of a partially ordered type. Bounds never hold values that aren't comparable,
+/// such as `NaN`, since [`Bounds::validate`](crate::Bounds::validate) rejects them. If one appears
+/// anyway, from bounds built without validating them, it is treated as equal instead of panicking.
+pub(crate) fn compare<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or(Ordering::Equal)
+}
+
+/// Whether the value isn't comparable with itself, like `NaN`.
+pub(crate) fn is_nan<T: PartialOrd>(x: &T) -> bool {
+    x.partial_cmp(x).is_none()
+}
+
+/// Orders two lower bounds by where they start. `None` is treated as negative infinity.
+pub(crate) fn cmp_lower_bounds<T: PartialOrd>(
+    a: &Option<Bound<T>>,
+    b: &Option<Bound<T>>,
+) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => {
+            compare(&a.value, &b.value).then_with(|| match (&a.bound_type, &b.bound_type) {
+                (BoundType::Inclusive, BoundType::Exclusive) => Ordering::Less,
+                (BoundType::Exclusive, BoundType::Inclusive) => Ordering::Greater,
+                _ => Ordering::Equal,
+            })
+        }
+    }
+}
+
+/// Orders two upper bounds by where they end. `None` is treated as positive infinity.
+pub(crate) fn cmp_upper_bounds<T: PartialOrd>(
+    a: &Option<Bound<T>>,
+    b: &Option<Bound<T>>,
+) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            compare(&a.value, &b.value).then_with(|| match (&a.bound_type, &b.bound_type) {
+                (BoundType::Inclusive, BoundType::Exclusive) => Ordering::Greater,
+                (BoundType::Exclusive, BoundType::Inclusive) => Ordering::Less,
+                _ => Ordering::Equal,
+            })
+        }
+    }
+}
+
+/// How a range ending at some upper bound meets a range starting at some lower bound.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) enum Junction {
+    /// At least one value lies between the two ranges.
+    Gap,
+    /// The ranges share an endpoint value, but only one of them includes it.
+    Adjacent,
+    /// The ranges share at least one value.
+    Overlap,
+}
+
+pub(crate) fn junction<T: PartialOrd>(
+    upper: &Option<Bound<T>>,
+    lower: &Option<Bound<T>>,
+) -> Junction {
+    match (upper, lower) {
+        (Some(upper), Some(lower)) => match compare(&upper.value, &lower.value) {
+            Ordering::Less => Junction::Gap,
+            Ordering::Greater => Junction::Overlap,
+            Ordering::Equal => match (&upper.bound_type, &lower.bound_type) {
+                (BoundType::Inclusive, BoundType::Inclusive) => Junction::Overlap,
+                (BoundType::Exclusive, BoundType::Exclusive) => Junction::Gap,
+                _ => Junction::Adjacent,
+            },
+        },
+        _ => Junction::Overlap,
+    }
+}
+
+impl<T: Zero> Bound<&T> {
+    /// The type of the product or quotient of this bound and `other`. Usually this is exclusive
+    /// if either of them is, but multiplying or dividing an inclusive zero gives exactly zero,
+    /// whatever the other operand is.
+    pub(crate) fn product_type(&self, other: &Bound<&T>) -> BoundType {
+        if self.bound_type == BoundType::Inclusive && self.value.is_zero() {
+            return BoundType::Inclusive;
+        }
+        if other.bound_type == BoundType::Inclusive && other.value.is_zero() {
+            return BoundType::Inclusive;
+        }
+        self.bound_type.combine(&other.bound_type)
+    }
+}
+
+impl<T: Clone> Bound<&T> {
+    pub fn cloned(self) -> Bound<T> {
+        Bound {
+            bound_type: self.bound_type,
+            value: self.value.clone(),
+        }
+    }
+}
+
 impl<T: Neg<Output = T>> Neg for Bound<T> {
     type Output = Self;
 
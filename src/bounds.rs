@@ -1,25 +1,44 @@
-use crate::bound::Bound;
 use crate::bound::BoundType::*;
+use crate::bound::{
+    cmp_lower_bounds, cmp_upper_bounds, compare, is_nan, junction, Bound, Junction,
+};
 use crate::bounds;
 use crate::comparison::Comparison;
+use crate::endpoint::{mul_signs, Endpoint};
+use crate::error::InvalidBounds;
+use crate::infinity::Infinity;
+use crate::relation::Relation;
+use crate::rounded::{RoundedAdd, RoundedDiv, RoundedMul, RoundedOps, RoundedSub};
 use crate::sign_bounds::SignBounds;
+use crate::split::Split;
 use crate::BoundType;
-use num::Zero;
+use num::rational::Ratio;
+use num::{BigInt, BigUint, Integer, One, Zero};
 use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Debug;
-use std::ops::{Add, RangeInclusive};
+use std::iter::{Product, Sum};
 use std::ops::Mul;
 use std::ops::Neg;
 use std::ops::RangeFrom;
 use std::ops::RangeFull;
 use std::ops::RangeTo;
 use std::ops::Sub;
+use std::ops::{Add, RangeInclusive};
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
 use std::ops::{Div, Range};
 
+/// A range of values, or a single exact value.
+///
+/// A `Range` with no values between its endpoints, such as `(1, 1)` or `[3, 2]`, is empty (see
+/// [`Bounds::is_empty`]). Arithmetic on empty bounds always produces empty bounds.
 #[derive(Eq, PartialEq, Clone)]
 pub enum Bounds<T> {
     Exact(T),
+    /// A range from a lower to an upper endpoint, where `None` is an unbounded side. This keeps
+    /// `Option` so that existing code building ranges still compiles. Code that needs to know
+    /// which infinity an unbounded side is should use [`Bounds::lower_endpoint`] and
+    /// [`Bounds::upper_endpoint`], which return an [`Endpoint`].
     Range(Option<Bound<T>>, Option<Bound<T>>),
 }
 
@@ -27,27 +46,33 @@ impl<T: Debug> fmt::Debug for Bounds<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Bounds::Exact(x) => write!(f, "{:?}", x),
-            Bounds::Range(a, b) => {
-                let left = match a {
-                    Some(a) => match a.bound_type {
-                        BoundType::Inclusive => format!("[{:?}", a.value),
-                        BoundType::Exclusive => format!("({:?}", a.value),
-                    },
-                    None => "(-∞".to_owned(),
-                };
-                let right = match b {
-                    Some(b) => match b.bound_type {
-                        BoundType::Inclusive => format!("{:?}]", b.value),
-                        BoundType::Exclusive => format!("{:?})", b.value),
-                    },
-                    None => "∞)".to_owned(),
-                };
-                write!(f, "{}, {}", left, right)
-            }
+            Bounds::Range(a, b) => fmt_range(a, b, f),
         }
     }
 }
 
+pub(crate) fn fmt_range<T: Debug>(
+    a: &Option<Bound<T>>,
+    b: &Option<Bound<T>>,
+    f: &mut fmt::Formatter,
+) -> fmt::Result {
+    let left = match a {
+        Some(a) => match a.bound_type {
+            BoundType::Inclusive => format!("[{:?}", a.value),
+            BoundType::Exclusive => format!("({:?}", a.value),
+        },
+        None => "(-∞".to_owned(),
+    };
+    let right = match b {
+        Some(b) => match b.bound_type {
+            BoundType::Inclusive => format!("{:?}]", b.value),
+            BoundType::Exclusive => format!("{:?})", b.value),
+        },
+        None => "∞)".to_owned(),
+    };
+    write!(f, "{}, {}", left, right)
+}
+
 impl<T> From<Range<T>> for Bounds<T> {
     fn from(range: Range<T>) -> Self {
         let start = Bound::inclusive(range.start);
@@ -95,252 +120,826 @@ impl<T: Neg<Output = T>> Neg for Bounds<T> {
     }
 }
 
-fn combine_opts<T, F: FnOnce(T, T) -> T>(a: Option<T>, b: Option<T>, func: F) -> Option<T> {
-    match (a, b) {
-        (Some(a), Some(b)) => Some(func(a, b)),
-        _ => None,
+/// Bounds holding a result that `T` may not be able to represent exactly, given that result
+/// rounded down and up.
+pub(crate) fn enclose<T: PartialOrd>(low: T, high: T) -> Bounds<T> {
+    if low == high {
+        Bounds::Exact(low)
+    } else {
+        Bounds::Range(Some(Bound::inclusive(low)), Some(Bound::inclusive(high)))
     }
 }
 
-impl<T: Add<T, Output = T> + Clone + Eq + Ord> Add for Bounds<T> {
+impl<T: RoundedAdd + Clone + PartialOrd> Add for Bounds<T> {
     type Output = Self;
 
     fn add(self, other: Self) -> Self::Output {
-        let opt_func = |a: Option<Bound<T>>, b: Option<Bound<T>>| -> Option<Bound<T>> {
-            combine_opts(a, b, |a, b| a.combine(b, |x, y| x + y))
-        };
-        match (self, other) {
-            (Bounds::Exact(a), Bounds::Exact(x)) => Bounds::Exact(a + x),
-            (Bounds::Range(a, b), Bounds::Range(x, y)) => {
-                Bounds::Range(opt_func(a, x), opt_func(b, y))
-            }
-            (Bounds::Exact(a), Bounds::Range(x, y)) | (Bounds::Range(x, y), Bounds::Exact(a)) => {
-                Bounds::Range(
-                    opt_func(Some(Bound::inclusive(a.clone())), x),
-                    opt_func(Some(Bound::inclusive(a)), y),
-                )
-            }
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_empty() {
+            return other;
         }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (&self, &other) {
+            return enclose(a.add_down(x), a.add_up(x));
+        }
+        let ((a, b), (x, y)) = (self.into_endpoints(), other.into_endpoints());
+        let low = a.add_with(x, |a, x| a.add_down(&x));
+        let high = b.add_with(y, |b, y| b.add_up(&y));
+        Bounds::Range(
+            low.and_then(Endpoint::into_bound),
+            high.and_then(Endpoint::into_bound),
+        )
     }
 }
 
-impl<T: Mul<T, Output = T> + Clone + Eq + Ord + Zero + Debug> Mul for Bounds<T> {
+impl<T: RoundedAdd + Clone + PartialOrd> Add<&Bounds<T>> for &Bounds<T> {
+    type Output = Bounds<T>;
+
+    fn add(self, other: &Bounds<T>) -> Self::Output {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            return enclose(a.add_down(x), a.add_up(x));
+        }
+        let low = self
+            .lower_endpoint()
+            .add_with(other.lower_endpoint(), T::add_down);
+        let high = self
+            .upper_endpoint()
+            .add_with(other.upper_endpoint(), T::add_up);
+        Bounds::Range(
+            low.and_then(Endpoint::into_bound),
+            high.and_then(Endpoint::into_bound),
+        )
+    }
+}
+
+impl<T: RoundedAdd + Clone + PartialOrd> Add<T> for Bounds<T> {
+    type Output = Self;
+
+    fn add(self, other: T) -> Self::Output {
+        if self.is_empty() {
+            return self;
+        }
+        match self {
+            Bounds::Exact(a) => enclose(a.add_down(&other), a.add_up(&other)),
+            Bounds::Range(a, b) => Bounds::Range(
+                a.map(|a| a.map(|x| x.add_down(&other))),
+                b.map(|b| b.map(|x| x.add_up(&other))),
+            ),
+        }
+    }
+}
+
+impl<T: RoundedMul + Clone + PartialOrd + Zero + Debug> Mul for Bounds<T> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self::Output {
-        let opt_func = |a: Option<Bound<T>>, b: Option<Bound<T>>| -> Option<Bound<T>> {
-            combine_opts(a, b, |a, b| a.combine(b, |x, y| x * y))
-        };
-        match (self, other) {
-            (Bounds::Exact(a), Bounds::Exact(x)) => Bounds::Exact(a * x),
-            (Bounds::Range(a, b), Bounds::Range(x, y)) => {
-                let left_signs = SignBounds::from_bounds(&a, &b);
-                let right_signs = SignBounds::from_bounds(&x, &y);
-                let mut negative_infinity = false;
-                let mut positive_infinity = false;
-                if a.is_none() {
-                    positive_infinity |= right_signs.below_zero;
-                    negative_infinity |= right_signs.above_zero
-                }
-                if b.is_none() {
-                    positive_infinity |= right_signs.above_zero;
-                    negative_infinity |= right_signs.below_zero
-                }
-                if x.is_none() {
-                    positive_infinity |= left_signs.below_zero;
-                    negative_infinity |= left_signs.above_zero
-                }
-                if y.is_none() {
-                    positive_infinity |= left_signs.above_zero;
-                    negative_infinity |= left_signs.below_zero
-                }
-                let mut lower_bound: Option<Bound<T>> = None;
-                let mut upper_bound: Option<Bound<T>> = None;
-                vec![
-                    combine_opts(a.clone(), x.clone(), |a, b| a * b),
-                    combine_opts(a.clone(), y.clone(), |a, b| a * b),
-                    combine_opts(b.clone(), x.clone(), |a, b| a * b),
-                    combine_opts(b.clone(), y.clone(), |a, b| a * b),
-                ]
-                .into_iter()
-                .for_each(|bound| {
-                    if let Some(bound) = bound {
-                        lower_bound = Some(match lower_bound.take() {
-                            Some(x) => Bound::lower_bound_min(bound.clone(), x),
-                            None => bound.clone(),
-                        });
-                        upper_bound = Some(match upper_bound.take() {
-                            Some(x) => Bound::upper_bound_max(bound, x),
-                            None => bound,
-                        });
-                    }
-                });
-                Bounds::Range(
-                    if negative_infinity { None } else { lower_bound },
-                    if positive_infinity { None } else { upper_bound },
-                )
-            }
-            (Bounds::Exact(a), Bounds::Range(x, y)) | (Bounds::Range(x, y), Bounds::Exact(a)) => {
-                if a.is_zero() {
-                    return Bounds::Exact(a);
-                }
-                let positive = { a >= T::zero() };
-                let bound_1 = opt_func(Some(Bound::inclusive(a.clone())), x);
-                let bound_2 = opt_func(Some(Bound::inclusive(a)), y);
-                if positive {
-                    Bounds::Range(bound_1, bound_2)
-                } else {
-                    Bounds::Range(bound_2, bound_1)
-                }
-            }
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_empty() {
+            return other;
         }
+        // products are computed from borrowed endpoints, so nothing is cloned but the results
+        &self * &other
     }
 }
 
-impl<T: Div<T, Output = T> + Clone + Eq + Ord + Zero + Debug> Div for Bounds<T> {
-    type Output = Option<Self>;
+impl<T: RoundedMul + Clone + PartialOrd + Zero + Debug> Mul<&Bounds<T>> for &Bounds<T> {
+    type Output = Bounds<T>;
 
-    fn div(self, other: Self) -> Self::Output {
-        let opt_func = |a: Option<Bound<T>>, b: Option<Bound<T>>| -> Option<Bound<T>> {
-            combine_opts(a, b, |a, b| a.combine(b, |x, y| x / y))
-        };
+    fn mul(self, other: &Bounds<T>) -> Self::Output {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
         match (self, other) {
-            (Bounds::Exact(a), Bounds::Exact(x)) => {
-                if x.is_zero() {
-                    None
-                } else {
-                    Some(Bounds::Exact(a / x))
-                }
+            (Bounds::Exact(a), _) | (_, Bounds::Exact(a)) if a.is_zero() => {
+                Bounds::Exact(a.clone())
             }
-            (Bounds::Range(a, b), Bounds::Range(x, y)) => {
-                debug_assert_bounds_order(&a, &b);
-                debug_assert_bounds_order(&x, &y);
-                let xy_sign_bounds = SignBounds::from_bounds(&x, &y);
-                if xy_sign_bounds.zero {
-                    return None;
-                }
+            (Bounds::Exact(a), Bounds::Exact(x)) => enclose(a.mul_down(x), a.mul_up(x)),
+            _ => mul_ranges(self, other, |a, b| Some((a.mul_down(b), a.mul_up(b)))),
+        }
+    }
+}
 
-                let left = a.map(|a| {
-                    let a_bound_type = a.bound_type;
-                    (Bounds::Exact(a.value) / Bounds::Range(x.clone(), y.clone())).map(|c| {
-                        if a_bound_type == BoundType::Exclusive {
-                            c.to_exclusive()
-                        } else {
-                            c
-                        }
-                    })
+/// The smallest range containing every one of `candidates`, which may include infinities.
+pub(crate) fn hull<T: PartialOrd + Clone, I: IntoIterator<Item = Endpoint<T>>>(
+    candidates: I,
+) -> Bounds<T> {
+    let mut negative_infinity = false;
+    let mut positive_infinity = false;
+    let mut lower_bound: Option<Bound<T>> = None;
+    let mut upper_bound: Option<Bound<T>> = None;
+    for candidate in candidates {
+        match candidate {
+            Endpoint::NegInfinity => negative_infinity = true,
+            Endpoint::PosInfinity => positive_infinity = true,
+            Endpoint::Finite(bound) => {
+                lower_bound = Some(match lower_bound.take() {
+                    Some(x) => Bound::lower_bound_min(bound.clone(), x),
+                    None => bound.clone(),
                 });
-                let right = b.map(|b| {
-                    let b_bound_type = b.bound_type;
-                    (Bounds::Exact(b.value) / Bounds::Range(x.clone(), y.clone())).map(|c| {
-                        if b_bound_type == BoundType::Exclusive {
-                            c.to_exclusive()
-                        } else {
-                            c
-                        }
-                    })
+                upper_bound = Some(match upper_bound.take() {
+                    Some(x) => Bound::upper_bound_max(bound, x),
+                    None => bound,
                 });
-                match (left, right) {
-                    (Some(left), Some(right)) => {
-                        if let (Some(left), Some(right)) = (left, right) {
-                            Some(left.merge(right))
-                        } else {
-                            None
-                        }
+            }
+        }
+    }
+    Bounds::Range(
+        if negative_infinity { None } else { lower_bound },
+        if positive_infinity { None } else { upper_bound },
+    )
+}
+
+/// The candidate endpoints for a result of `bound_type`, given that result rounded down and up.
+pub(crate) fn rounded_candidates<T>(
+    bound_type: BoundType,
+    (low, high): (T, T),
+) -> [Option<Endpoint<T>>; 2] {
+    [
+        Some(Endpoint::Finite(Bound {
+            bound_type: bound_type.clone(),
+            value: low,
+        })),
+        Some(Endpoint::Finite(Bound {
+            bound_type,
+            value: high,
+        })),
+    ]
+}
+
+/// Like [`rounded_candidates`], but an enclosure that is `NaN`, such as `∞ / ∞` for floats, could
+/// be any value of `sign`, so it gives zero and the infinity of that sign instead.
+fn comparable_candidates<T: PartialOrd + Zero>(
+    bound_type: BoundType,
+    (low, high): (T, T),
+    sign: Ordering,
+) -> [Option<Endpoint<T>>; 2] {
+    if is_nan(&low) || is_nan(&high) {
+        return [
+            Some(Endpoint::Finite(Bound::inclusive(T::zero()))),
+            Some(Endpoint::infinity(sign)),
+        ];
+    }
+    rounded_candidates(bound_type, (low, high))
+}
+
+/// Multiplies two ranges, using `product` to enclose the products of finite endpoints between a
+/// value rounded down and one rounded up. Since the product is monotonic in each factor, its
+/// extremes are among the products of the endpoints. If `product` returns `None` because a
+/// product doesn't fit in `T`, the side of the result that product would have extended is left
+/// unbounded.
+pub(crate) fn mul_ranges<T, F>(left: &Bounds<T>, right: &Bounds<T>, product: F) -> Bounds<T>
+where
+    T: Clone + PartialOrd + Zero,
+    F: Fn(&T, &T) -> Option<(T, T)>,
+{
+    let (a, b) = (left.lower_endpoint(), left.upper_endpoint());
+    let (x, y) = (right.lower_endpoint(), right.upper_endpoint());
+    let corners = [(&a, &x), (&a, &y), (&b, &x), (&b, &y)];
+    hull(
+        corners
+            .into_iter()
+            .flat_map(|(l, r)| {
+                let sign = mul_signs(l.sign(), r.sign());
+                match (l, r) {
+                    // a zero factor gives exactly zero, even next to an infinite value, where a
+                    // float product would be `NaN`
+                    (Endpoint::Finite(l), Endpoint::Finite(r))
+                        if l.value.is_zero() || r.value.is_zero() =>
+                    {
+                        let zero = Bound {
+                            bound_type: l.product_type(r),
+                            value: T::zero(),
+                        };
+                        [Some(Endpoint::Finite(zero)), None]
                     }
-                    (Some(left), None) => {
-                        if xy_sign_bounds.above_zero {
-                            left.map(|x| x.remove_upper_bound())
-                        } else {
-                            left.map(|x| x.remove_lower_bound())
-                        }
+                    (Endpoint::Finite(l), Endpoint::Finite(r)) => match product(l.value, r.value) {
+                        Some(values) => comparable_candidates(l.product_type(r), values, sign),
+                        None => [Some(Endpoint::infinity(sign)), None],
+                    },
+                    (l, r) => [l.clone().cloned().mul_unbounded(r.clone().cloned()), None],
+                }
+            })
+            .flatten(),
+    )
+}
+
+/// Divides two ranges, using `quotient` to enclose the quotients of finite endpoints, or returns
+/// `None` if the divisor contains zero. As in [`mul_ranges`], the extremes are among the
+/// quotients of the endpoints, and a quotient that doesn't fit in `T` leaves its side of the
+/// result unbounded.
+pub(crate) fn div_ranges<T, F>(
+    dividend: &Bounds<T>,
+    divisor: &Bounds<T>,
+    quotient: F,
+) -> Option<Bounds<T>>
+where
+    T: Clone + PartialOrd + Zero,
+    F: Fn(&T, &T) -> Option<(T, T)>,
+{
+    let divisor_signs = SignBounds::from_bounds(divisor);
+    if divisor_signs.zero {
+        return None;
+    }
+    let divisor_sign = if divisor_signs.above_zero {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    };
+    let (a, b) = (dividend.lower_endpoint(), dividend.upper_endpoint());
+    let (x, y) = (divisor.lower_endpoint(), divisor.upper_endpoint());
+    let corners = [(&a, &x), (&a, &y), (&b, &x), (&b, &y)];
+    Some(hull(
+        corners
+            .into_iter()
+            .flat_map(|(d, e)| {
+                let sign = mul_signs(d.sign(), divisor_sign);
+                match (d, e) {
+                    (Endpoint::Finite(d), _) if d.value.is_zero() => {
+                        [Some(Endpoint::Finite(d.clone().cloned())), None]
                     }
-                    (None, Some(right)) => {
-                        if xy_sign_bounds.above_zero {
-                            right.map(|x| x.remove_lower_bound())
-                        } else {
-                            right.map(|x| x.remove_upper_bound())
+                    // dividing by values that approach zero grows without bound
+                    (Endpoint::Finite(_), Endpoint::Finite(e)) if e.value.is_zero() => {
+                        [Some(Endpoint::infinity(sign)), None]
+                    }
+                    (Endpoint::Finite(d), Endpoint::Finite(e)) => {
+                        match quotient(d.value, e.value) {
+                            Some(values) => comparable_candidates(d.product_type(e), values, sign),
+                            None => [Some(Endpoint::infinity(sign)), None],
                         }
                     }
-                    (None, None) => Some(bounds!(,)),
+                    // dividing by ever larger values approaches zero, but never reaches it
+                    (Endpoint::Finite(_), _) => {
+                        [Some(Endpoint::Finite(Bound::exclusive(T::zero()))), None]
+                    }
+                    (_, Endpoint::Finite(_)) => [Some(Endpoint::infinity(sign)), None],
+                    _ => [None, None],
                 }
+            })
+            .flatten(),
+    ))
+}
+
+impl<T: RoundedMul + Clone + PartialOrd + Zero + Debug> Mul<T> for Bounds<T> {
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_zero() {
+            return Bounds::Exact(other);
+        }
+        // a zero endpoint stays exactly zero, even when `other` is infinite
+        let product = |x: T, round: fn(&T, &T) -> T| {
+            if x.is_zero() {
+                x
+            } else {
+                round(&x, &other)
             }
-            (Bounds::Exact(a), Bounds::Range(x, y)) => {
-                if SignBounds::from_bounds(&x, &y).zero {
-                    return None;
-                }
-                if a.is_zero() {
-                    return Some(Bounds::Exact(T::zero()));
-                }
-                if x == Some(Bound::exclusive(T::zero())) {
-                    let positive = a >= T::zero();
-                    let bound = Some(match y {
-                        Some(y) => Bound::inclusive(a.clone()).combine(y, |c, d| c / d),
-                        None => Bound::exclusive(T::zero()),
-                    });
-                    return Some(if positive {
-                        Bounds::Range(bound, None)
-                    } else {
-                        Bounds::Range(None, bound)
-                    });
-                }
-                if y == Some(Bound::exclusive(T::zero())) {
-                    let positive = a >= T::zero();
-                    let bound = Some(match x {
-                        Some(x) => Bound::inclusive(a.clone()).combine(x, |c, d| c / d),
-                        None => Bound::exclusive(T::zero()),
-                    });
-                    return Some(if positive {
-                        Bounds::Range(None, bound)
-                    } else {
-                        Bounds::Range(bound, None)
-                    });
-                }
-                let positive = a >= T::zero();
-                let bound_1 = opt_func(Some(Bound::inclusive(a.clone())), x)
-                    .unwrap_or_else(|| Bound::exclusive(T::zero()));
-                let bound_2 = opt_func(Some(Bound::inclusive(a)), y)
-                    .unwrap_or_else(|| Bound::exclusive(T::zero()));
-                Some(if positive {
-                    Bounds::Range(Some(bound_2), Some(bound_1))
+        };
+        match self {
+            Bounds::Exact(a) if a.is_zero() => Bounds::Exact(a),
+            Bounds::Exact(a) => enclose(a.mul_down(&other), a.mul_up(&other)),
+            Bounds::Range(x, y) => {
+                let lower = |x: Bound<T>| x.map(|x| product(x, T::mul_down));
+                let upper = |x: Bound<T>| x.map(|x| product(x, T::mul_up));
+                if other > T::zero() {
+                    Bounds::Range(x.map(lower), y.map(upper))
                 } else {
-                    Bounds::Range(Some(bound_1), Some(bound_2))
-                })
+                    Bounds::Range(y.map(lower), x.map(upper))
+                }
             }
-            (Bounds::Range(x, y), Bounds::Exact(a)) => {
-                if a.is_zero() {
+        }
+    }
+}
+
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> Div for Bounds<T> {
+    type Output = Option<Self>;
+
+    fn div(self, other: Self) -> Self::Output {
+        if self.is_empty() {
+            return Some(self);
+        }
+        if other.is_empty() {
+            return Some(other);
+        }
+        // quotients are computed from borrowed endpoints, like products
+        &self / &other
+    }
+}
+
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> Div<&Bounds<T>> for &Bounds<T> {
+    type Output = Option<Bounds<T>>;
+
+    fn div(self, other: &Bounds<T>) -> Self::Output {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        if other.is_empty() {
+            return Some(other.clone());
+        }
+        match (self, other) {
+            (_, Bounds::Exact(x)) if x.is_zero() => None,
+            (Bounds::Exact(a), Bounds::Exact(x)) => Some(enclose(a.div_down(x), a.div_up(x))),
+            (Bounds::Exact(a), _) if a.is_zero() => {
+                if SignBounds::from_bounds(other).zero {
                     return None;
                 }
-                let positive = a >= T::zero();
-                let bound_1 = opt_func(x, Some(Bound::inclusive(a.clone())));
-                let bound_2 = opt_func(y, Some(Bound::inclusive(a)));
-                Some(if positive {
-                    Bounds::Range(bound_1, bound_2)
-                } else {
-                    Bounds::Range(bound_2, bound_1)
-                })
+                Some(self.clone())
             }
+            _ => div_ranges(self, other, |a, b| Some((a.div_down(b), a.div_up(b)))),
         }
     }
 }
 
-impl<T: Neg<Output = T> + Add<T, Output = T> + Clone + Eq + Ord> Sub for Bounds<T> {
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> Div<T> for Bounds<T> {
+    type Output = Option<Self>;
+
+    fn div(self, other: T) -> Self::Output {
+        if other.is_zero() {
+            return None;
+        }
+        if self.is_empty() {
+            return Some(self);
+        }
+        Some(match self {
+            Bounds::Exact(a) => enclose(a.div_down(&other), a.div_up(&other)),
+            Bounds::Range(x, y) => {
+                let lower = |x: Bound<T>| x.map(|x| x.div_down(&other));
+                let upper = |x: Bound<T>| x.map(|x| x.div_up(&other));
+                if other > T::zero() {
+                    Bounds::Range(x.map(lower), y.map(upper))
+                } else {
+                    Bounds::Range(y.map(lower), x.map(upper))
+                }
+            }
+        })
+    }
+}
+
+impl<T: RoundedSub + Clone + PartialOrd> Sub for Bounds<T> {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self::Output {
-        self + -other
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_empty() {
+            return other;
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (&self, &other) {
+            return enclose(a.sub_down(x), a.sub_up(x));
+        }
+        let ((a, b), (x, y)) = (self.into_endpoints(), other.into_endpoints());
+        let low = a.sub_with(y, |a, y| a.sub_down(&y));
+        let high = b.sub_with(x, |b, x| b.sub_up(&x));
+        Bounds::Range(
+            low.and_then(Endpoint::into_bound),
+            high.and_then(Endpoint::into_bound),
+        )
     }
 }
 
-impl<T> Bounds<T> {
-    fn to_exclusive(self) -> Bounds<T> {
+impl<T: RoundedSub + Clone + PartialOrd> Sub<&Bounds<T>> for &Bounds<T> {
+    type Output = Bounds<T>;
+
+    fn sub(self, other: &Bounds<T>) -> Self::Output {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            return enclose(a.sub_down(x), a.sub_up(x));
+        }
+        let low = self
+            .lower_endpoint()
+            .sub_with(other.upper_endpoint(), T::sub_down);
+        let high = self
+            .upper_endpoint()
+            .sub_with(other.lower_endpoint(), T::sub_up);
+        Bounds::Range(
+            low.and_then(Endpoint::into_bound),
+            high.and_then(Endpoint::into_bound),
+        )
+    }
+}
+
+/// Implements the operators between owned and borrowed bounds by borrowing the owned side.
+macro_rules! forward_ref_ops {
+    ($imp:ident, $method:ident, $output:ty, $($bounds:tt)+) => {
+        impl<'a, T: $($bounds)+> $imp<&'a Bounds<T>> for Bounds<T> {
+            type Output = $output;
+
+            fn $method(self, other: &'a Bounds<T>) -> Self::Output {
+                $imp::$method(&self, other)
+            }
+        }
+
+        impl<'a, T: $($bounds)+> $imp<Bounds<T>> for &'a Bounds<T> {
+            type Output = $output;
+
+            fn $method(self, other: Bounds<T>) -> Self::Output {
+                $imp::$method(self, &other)
+            }
+        }
+    };
+}
+
+forward_ref_ops!(Add, add, Bounds<T>, RoundedAdd + Clone + PartialOrd);
+forward_ref_ops!(Sub, sub, Bounds<T>, RoundedSub + Clone + PartialOrd);
+forward_ref_ops!(
+    Mul,
+    mul,
+    Bounds<T>,
+    RoundedMul + Clone + PartialOrd + Zero + Debug
+);
+forward_ref_ops!(
+    Div,
+    div,
+    Option<Bounds<T>>,
+    RoundedDiv + Clone + PartialOrd + Zero + Debug
+);
+
+/// Implements a compound assignment operator in terms of its binary operator, for owned bounds,
+/// borrowed bounds and scalar values.
+macro_rules! impl_assign_ops {
+    ($imp:ident, $method:ident, $op:ident, $op_method:ident, $($bounds:tt)+) => {
+        impl<T: $($bounds)+> $imp for Bounds<T> {
+            fn $method(&mut self, other: Bounds<T>) {
+                let this = std::mem::replace(self, Bounds::Range(None, None));
+                *self = $op::$op_method(this, other);
+            }
+        }
+
+        impl<T: $($bounds)+> $imp<&Bounds<T>> for Bounds<T> {
+            fn $method(&mut self, other: &Bounds<T>) {
+                *self = $op::$op_method(&*self, other);
+            }
+        }
+
+        impl<T: $($bounds)+> $imp<T> for Bounds<T> {
+            fn $method(&mut self, other: T) {
+                let this = std::mem::replace(self, Bounds::Range(None, None));
+                *self = $op::$op_method(this, other);
+            }
+        }
+    };
+}
+
+impl_assign_ops!(
+    AddAssign,
+    add_assign,
+    Add,
+    add,
+    RoundedAdd + Clone + PartialOrd
+);
+impl_assign_ops!(
+    SubAssign,
+    sub_assign,
+    Sub,
+    sub,
+    RoundedSub + Clone + PartialOrd
+);
+impl_assign_ops!(
+    MulAssign,
+    mul_assign,
+    Mul,
+    mul,
+    RoundedMul + Clone + PartialOrd + Zero + Debug
+);
+
+/// Division can fail when the divisor contains zero. Since `/=` has no way to report that, the
+/// result is widened to `(-∞, ∞)` instead, which still contains every possible quotient. Use `/`
+/// directly to detect this case.
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> DivAssign for Bounds<T> {
+    fn div_assign(&mut self, other: Bounds<T>) {
+        let this = std::mem::replace(self, Bounds::Range(None, None));
+        if let Some(quotient) = this / other {
+            *self = quotient;
+        }
+    }
+}
+
+/// Widens to `(-∞, ∞)` if the divisor contains zero, like `DivAssign<Bounds<T>>`.
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> DivAssign<&Bounds<T>> for Bounds<T> {
+    fn div_assign(&mut self, other: &Bounds<T>) {
+        *self = (&*self / other).unwrap_or(Bounds::Range(None, None));
+    }
+}
+
+/// Widens to `(-∞, ∞)` if the divisor is zero, like `DivAssign<Bounds<T>>`.
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> DivAssign<T> for Bounds<T> {
+    fn div_assign(&mut self, other: T) {
+        let this = std::mem::replace(self, Bounds::Range(None, None));
+        if let Some(quotient) = this / other {
+            *self = quotient;
+        }
+    }
+}
+
+impl<T: RoundedAdd + Clone + PartialOrd + Zero> Sum for Bounds<T> {
+    fn sum<I: Iterator<Item = Bounds<T>>>(iter: I) -> Self {
+        iter.fold(Bounds::Exact(T::zero()), Add::add)
+    }
+}
+
+impl<'a, T: RoundedAdd + Clone + PartialOrd + Zero> Sum<&'a Bounds<T>> for Bounds<T> {
+    fn sum<I: Iterator<Item = &'a Bounds<T>>>(iter: I) -> Self {
+        iter.fold(Bounds::Exact(T::zero()), |acc, x| acc + x)
+    }
+}
+
+impl<T: RoundedMul + Clone + PartialOrd + Zero + One + Debug> Product for Bounds<T> {
+    fn product<I: Iterator<Item = Bounds<T>>>(iter: I) -> Self {
+        iter.fold(Bounds::Exact(T::one()), Mul::mul)
+    }
+}
+
+impl<'a, T: RoundedMul + Clone + PartialOrd + Zero + One + Debug> Product<&'a Bounds<T>>
+    for Bounds<T>
+{
+    fn product<I: Iterator<Item = &'a Bounds<T>>>(iter: I) -> Self {
+        iter.fold(Bounds::Exact(T::one()), |acc, x| acc * x)
+    }
+}
+
+impl<T: RoundedSub + Clone + PartialOrd> Sub<T> for Bounds<T> {
+    type Output = Self;
+
+    fn sub(self, other: T) -> Self::Output {
+        if self.is_empty() {
+            return self;
+        }
+        match self {
+            Bounds::Exact(a) => enclose(a.sub_down(&other), a.sub_up(&other)),
+            Bounds::Range(a, b) => Bounds::Range(
+                a.map(|a| a.map(|x| x.sub_down(&other))),
+                b.map(|b| b.map(|x| x.sub_up(&other))),
+            ),
+        }
+    }
+}
+
+impl<T: RoundedSub + Clone + PartialOrd> Bounds<T> {
+    /// Returns `value - self`.
+    fn subtract_from(self, value: T) -> Bounds<T> {
+        if self.is_empty() {
+            return self;
+        }
         match self {
-            Bounds::Exact(x) => Bounds::Exact(x),
+            Bounds::Exact(a) => enclose(value.sub_down(&a), value.sub_up(&a)),
+            Bounds::Range(a, b) => Bounds::Range(
+                b.map(|b| b.map(|x| value.sub_down(&x))),
+                a.map(|a| a.map(|x| value.sub_up(&x))),
+            ),
+        }
+    }
+}
+
+/// Implements the scalar operators with the scalar on the left, such as `2 * bounds!(1, 3)`.
+/// These can't be implemented generically, so they are listed for each supported type.
+macro_rules! impl_scalar_ops {
+    ($($t:ty),*) => {$(
+        impl Add<Bounds<$t>> for $t {
+            type Output = Bounds<$t>;
+
+            fn add(self, other: Bounds<$t>) -> Self::Output {
+                other + self
+            }
+        }
+
+        impl Sub<Bounds<$t>> for $t {
+            type Output = Bounds<$t>;
+
+            fn sub(self, other: Bounds<$t>) -> Self::Output {
+                other.subtract_from(self)
+            }
+        }
+
+        impl Mul<Bounds<$t>> for $t {
+            type Output = Bounds<$t>;
+
+            fn mul(self, other: Bounds<$t>) -> Self::Output {
+                other * self
+            }
+        }
+
+        impl Div<Bounds<$t>> for $t {
+            type Output = Option<Bounds<$t>>;
+
+            fn div(self, other: Bounds<$t>) -> Self::Output {
+                Bounds::Exact(self) / other
+            }
+        }
+    )*};
+}
+
+impl_scalar_ops!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, BigInt, BigUint
+);
+
+impl<T: Clone + Integer> Add<Bounds<Ratio<T>>> for Ratio<T> {
+    type Output = Bounds<Ratio<T>>;
+
+    fn add(self, other: Bounds<Ratio<T>>) -> Self::Output {
+        other + self
+    }
+}
+
+impl<T: Clone + Integer> Sub<Bounds<Ratio<T>>> for Ratio<T> {
+    type Output = Bounds<Ratio<T>>;
+
+    fn sub(self, other: Bounds<Ratio<T>>) -> Self::Output {
+        other.subtract_from(self)
+    }
+}
+
+impl<T: Clone + Integer + Debug> Mul<Bounds<Ratio<T>>> for Ratio<T> {
+    type Output = Bounds<Ratio<T>>;
+
+    fn mul(self, other: Bounds<Ratio<T>>) -> Self::Output {
+        other * self
+    }
+}
+
+impl<T: Clone + Integer + Debug> Div<Bounds<Ratio<T>>> for Ratio<T> {
+    type Output = Option<Bounds<Ratio<T>>>;
+
+    fn div(self, other: Bounds<Ratio<T>>) -> Self::Output {
+        Bounds::Exact(self) / other
+    }
+}
+
+impl<T: RoundedDiv + Clone + PartialOrd + Zero + Debug> Bounds<T> {
+    /// Divides using extended interval arithmetic. Unlike `/`, a divisor that contains zero
+    /// still gives an answer: the quotient splits into a negative and a positive piece, such as
+    /// `[1, 2] / [-1, 1] = (-∞, -1] ∪ [1, ∞)`. Dividing by exactly zero gives an empty result,
+    /// and dividing bounds containing zero by a divisor containing zero gives `(-∞, ∞)`.
+    pub fn div_extended(self, other: Self) -> Split<T> {
+        if self.is_empty() || other.is_empty() {
+            return Split::Empty;
+        }
+        if !other.contains(&T::zero()) {
+            return Split::from_pieces(self / other, None);
+        }
+        let negative = other.intersection(&Bounds::Range(None, Some(Bound::exclusive(T::zero()))));
+        let positive = other.intersection(&Bounds::Range(Some(Bound::exclusive(T::zero())), None));
+        if negative.is_none() && positive.is_none() {
+            return Split::Empty;
+        }
+        if self.contains(&T::zero()) {
+            return Split::One(Bounds::Range(None, None));
+        }
+        let above_zero = self.compare_to(&Bounds::Exact(T::zero())) == Comparison::Greater;
+        let below = negative.and_then(|x| self.clone() / x);
+        let above = positive.and_then(|x| self / x);
+        if above_zero {
+            Split::from_pieces(below, above)
+        } else {
+            Split::from_pieces(above, below)
+        }
+    }
+
+    /// Returns `1 / self`, using the same rules as [`Bounds::div_extended`].
+    pub fn recip(self) -> Split<T>
+    where
+        T: One,
+    {
+        Bounds::Exact(T::one()).div_extended(self)
+    }
+}
+
+/// Raises a non-negative `value` to `exp` by repeated squaring, rounding each product with `mul`.
+/// Products of non-negative values grow with their factors, so rounding every step in the same
+/// direction rounds the result in that direction.
+fn pow_with<T: One + Clone>(value: &T, exp: u32, mul: fn(&T, &T) -> T) -> T {
+    let mut result = T::one();
+    let mut base = value.clone();
+    let mut exp = exp;
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = mul(&result, &base);
+        }
+        exp /= 2;
+        if exp > 0 {
+            base = mul(&base, &base);
+        }
+    }
+    result
+}
+
+/// `value` raised to `exp`, rounded down. A negative value is raised through its absolute value.
+fn pow_down<T: RoundedOps + Clone + PartialOrd + Zero + One>(value: &T, exp: u32) -> T {
+    if *value >= T::zero() {
+        pow_with(value, exp, T::mul_down)
+    } else if exp % 2 == 0 {
+        pow_with(&T::zero().sub_down(value), exp, T::mul_down)
+    } else {
+        T::zero().sub_down(&pow_with(&T::zero().sub_up(value), exp, T::mul_up))
+    }
+}
+
+/// `value` raised to `exp`, rounded up.
+fn pow_up<T: RoundedOps + Clone + PartialOrd + Zero + One>(value: &T, exp: u32) -> T {
+    if *value >= T::zero() {
+        pow_with(value, exp, T::mul_up)
+    } else if exp % 2 == 0 {
+        pow_with(&T::zero().sub_up(value), exp, T::mul_up)
+    } else {
+        T::zero().sub_up(&pow_with(&T::zero().sub_down(value), exp, T::mul_down))
+    }
+}
+
+impl<T: RoundedOps + Clone + PartialOrd + Zero + One> Bounds<T> {
+    /// Raises the bounds to the power `exp`. Unlike repeated multiplication, every factor is
+    /// known to be the same value, so `[-2, 3].pow(2)` is `[0, 9]` rather than `[-6, 9]`.
+    pub fn pow(self, exp: u32) -> Bounds<T> {
+        if self.is_empty() {
+            return self;
+        }
+        if exp == 0 {
+            return Bounds::Exact(T::one());
+        }
+        let lower = |x: Bound<T>| x.map(|x| pow_down(&x, exp));
+        let upper = |x: Bound<T>| x.map(|x| pow_up(&x, exp));
+        let signs = SignBounds::from_bounds(&self);
+        match self {
+            Bounds::Exact(x) => enclose(pow_down(&x, exp), pow_up(&x, exp)),
             Bounds::Range(a, b) => {
-                Bounds::Range(a.map(|x| x.to_exclusive()), b.map(|x| x.to_exclusive()))
+                if exp % 2 == 1 || !signs.below_zero {
+                    Bounds::Range(a.map(lower), b.map(upper))
+                } else if !signs.above_zero {
+                    Bounds::Range(b.map(lower), a.map(upper))
+                } else {
+                    // an even power of either infinity is positive infinity
+                    let power = |x: Endpoint<T>| match x {
+                        Endpoint::Finite(x) => Endpoint::Finite(upper(x)),
+                        _ => Endpoint::PosInfinity,
+                    };
+                    let high = power(Endpoint::lower(a)).upper_max(power(Endpoint::upper(b)));
+                    Bounds::Range(Some(Bound::inclusive(T::zero())), high.into_bound())
+                }
             }
         }
     }
+
+    /// Same as `pow(2)`.
+    pub fn square(self) -> Bounds<T> {
+        self.pow(2)
+    }
+}
+
+impl<T> Bounds<T> {
+    /// The start of the bounds, which is the value itself for `Exact`.
+    pub fn lower_endpoint(&self) -> Endpoint<&T> {
+        Endpoint::lower(self.lower())
+    }
+
+    /// The end of the bounds, which is the value itself for `Exact`.
+    pub fn upper_endpoint(&self) -> Endpoint<&T> {
+        Endpoint::upper(self.upper())
+    }
+
+    /// Takes both endpoints out of the bounds, cloning only the value of `Exact`, which is both.
+    pub(crate) fn into_endpoints(self) -> (Endpoint<T>, Endpoint<T>)
+    where
+        T: Clone,
+    {
+        match self {
+            Bounds::Exact(x) => (
+                Endpoint::Finite(Bound::inclusive(x.clone())),
+                Endpoint::Finite(Bound::inclusive(x)),
+            ),
+            Bounds::Range(a, b) => (Endpoint::lower(a), Endpoint::upper(b)),
+        }
+    }
+
+    pub(crate) fn lower(&self) -> Option<Bound<&T>> {
+        match self {
+            Bounds::Exact(x) => Some(Bound::inclusive(x)),
+            Bounds::Range(a, _b) => a.as_ref().map(Bound::as_ref),
+        }
+    }
+    pub(crate) fn upper(&self) -> Option<Bound<&T>> {
+        match self {
+            Bounds::Exact(x) => Some(Bound::inclusive(x)),
+            Bounds::Range(_a, b) => b.as_ref().map(Bound::as_ref),
+        }
+    }
     pub fn remove_upper_bound(self) -> Bounds<T> {
         match self {
             Bounds::Exact(x) => bounds!(x,),
@@ -355,8 +954,13 @@ impl<T> Bounds<T> {
     }
 }
 
-impl<T: Sub<Output = T> + Clone + Zero> Bounds<T> {
+impl<T: Sub<Output = T> + Clone + Zero + PartialOrd> Bounds<T> {
+    /// The distance between the endpoints, or `None` if unbounded. Empty bounds have a size of
+    /// zero.
     pub fn size(&self) -> Option<T> {
+        if self.is_empty() {
+            return Some(T::zero());
+        }
         match *self {
             Bounds::Exact(_) => Some(T::zero()),
             Bounds::Range(None, _) | Bounds::Range(_, None) => None,
@@ -365,10 +969,18 @@ impl<T: Sub<Output = T> + Clone + Zero> Bounds<T> {
     }
 }
 
-impl<T: Eq + Ord> Bounds<T> {
+impl<T: PartialOrd> Bounds<T> {
+    /// Returns the smallest bounds covering both. Merging with empty bounds returns the other
+    /// bounds unchanged.
     pub fn merge(self, other: Self) -> Self {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return self;
+        }
         match (self, other) {
-            (Bounds::Exact(a), Bounds::Exact(x)) => match a.cmp(&x) {
+            (Bounds::Exact(a), Bounds::Exact(x)) => match compare(&a, &x) {
                 Ordering::Equal => Bounds::Exact(a),
                 Ordering::Less => {
                     Bounds::Range(Some(Bound::inclusive(a)), Some(Bound::inclusive(x)))
@@ -378,8 +990,6 @@ impl<T: Eq + Ord> Bounds<T> {
                 }
             },
             (Bounds::Range(a, b), Bounds::Range(x, y)) => {
-                debug_assert_bounds_order(&a, &b);
-                debug_assert_bounds_order(&x, &y);
                 let high = match (b, y) {
                     (None, None) => None,
                     (Some(_val), None) => None,
@@ -430,90 +1040,287 @@ impl<T: Eq + Ord> Bounds<T> {
     pub fn range(start: Bound<T>, end: Bound<T>) -> Self {
         Bounds::Range(Some(start), Some(end))
     }
+
+    /// Returns true if `value` lies within the bounds.
+    pub fn contains(&self, value: &T) -> bool {
+        let above_lower = match self.lower() {
+            Some(a) => match a.bound_type {
+                Inclusive => value >= a.value,
+                Exclusive => value > a.value,
+            },
+            None => true,
+        };
+        let below_upper = match self.upper() {
+            Some(b) => match b.bound_type {
+                Inclusive => value <= b.value,
+                Exclusive => value < b.value,
+            },
+            None => true,
+        };
+        above_lower && below_upper
+    }
+
+    /// Returns true if every value in `other` is also in `self`. Empty bounds are contained by
+    /// everything.
+    pub fn contains_bounds(&self, other: &Bounds<T>) -> bool {
+        if other.is_empty() {
+            return true;
+        }
+        !self.is_empty()
+            && cmp_lower_bounds(&self.lower(), &other.lower()) != Ordering::Greater
+            && cmp_upper_bounds(&self.upper(), &other.upper()) != Ordering::Less
+    }
+
+    /// Same as [`Bounds::contains_bounds`].
+    pub fn is_superset_of(&self, other: &Bounds<T>) -> bool {
+        self.contains_bounds(other)
+    }
+
     pub fn intersects(&self, other: &Bounds<T>) -> bool {
         self.compare_to(other) == Comparison::Intersects
     }
 
+    /// A coarse view of [`Bounds::relation`]. If either bounds are empty, the result is `Less`,
+    /// whichever side the empty bounds are on. So unlike for other bounds, `b.compare_to(&a)`
+    /// isn't `-a.compare_to(&b)` when one of them is empty.
     pub fn compare_to(&self, other: &Bounds<T>) -> Comparison {
-        match (self, other) {
-            (&Bounds::Exact(ref a), &Bounds::Exact(ref x)) => match a.cmp(&x) {
-                Ordering::Equal => Comparison::Intersects,
-                Ordering::Less => Comparison::Less,
-                Ordering::Greater => Comparison::Greater,
-            },
-            (&Bounds::Range(ref a, ref b), &Bounds::Range(ref x, ref y)) => {
-                debug_assert_bounds_order(a, b);
-                debug_assert_bounds_order(x, y);
-                if let (&Some(ref a), &Some(ref y)) = (a, y) {
-                    if a.bound_type == Inclusive && y.bound_type == Inclusive {
-                        if a.value > y.value {
-                            return Comparison::Greater;
-                        }
-                    } else {
-                        if a.value >= y.value {
-                            return Comparison::Greater;
-                        }
-                    }
-                }
-                if let (&Some(ref b), &Some(ref x)) = (b, x) {
-                    if b.bound_type == Inclusive && x.bound_type == Inclusive {
-                        if b.value < x.value {
-                            return Comparison::Less;
-                        }
-                    } else {
-                        if b.value <= x.value {
-                            return Comparison::Less;
-                        }
-                    }
-                }
-                Comparison::Intersects
+        self.relation(other).into()
+    }
+
+    /// Returns how `self` is positioned relative to `other`, as one of Allen's interval
+    /// relations. Empty bounds share no values with anything, so if either bounds are empty, the
+    /// result is `Before`, whichever side the empty bounds are on. This breaks the usual symmetry
+    /// of the relations: `b.relation(&a)` is `-a.relation(&b)` only when neither is empty.
+    pub fn relation(&self, other: &Bounds<T>) -> Relation {
+        if self.is_empty() || other.is_empty() {
+            return Relation::Before;
+        }
+        let (a, b) = (self.lower(), self.upper());
+        let (x, y) = (other.lower(), other.upper());
+        match junction(&b, &x) {
+            Junction::Gap => return Relation::Before,
+            Junction::Adjacent => return Relation::Meets,
+            Junction::Overlap => {}
+        }
+        match junction(&y, &a) {
+            Junction::Gap => return Relation::After,
+            Junction::Adjacent => return Relation::MetBy,
+            Junction::Overlap => {}
+        }
+        match (cmp_lower_bounds(&a, &x), cmp_upper_bounds(&b, &y)) {
+            (Ordering::Equal, Ordering::Equal) => Relation::Equals,
+            (Ordering::Equal, Ordering::Less) => Relation::Starts,
+            (Ordering::Equal, Ordering::Greater) => Relation::StartedBy,
+            (Ordering::Greater, Ordering::Equal) => Relation::Finishes,
+            (Ordering::Less, Ordering::Equal) => Relation::FinishedBy,
+            (Ordering::Greater, Ordering::Less) => Relation::During,
+            (Ordering::Less, Ordering::Greater) => Relation::Contains,
+            (Ordering::Less, Ordering::Less) => Relation::Overlaps,
+            (Ordering::Greater, Ordering::Greater) => Relation::OverlappedBy,
+        }
+    }
+}
+
+impl<T: PartialOrd + Infinity + Clone> Bounds<T> {
+    /// Creates a range, returning an error if no values lie between the endpoints.
+    pub fn try_range(
+        start: Option<Bound<T>>,
+        end: Option<Bound<T>>,
+    ) -> Result<Self, InvalidBounds<T>> {
+        Bounds::Range(start, end).validate()
+    }
+
+    /// Checks that the bounds hold at least one value, and that no endpoint is `NaN`, returning
+    /// an error holding the endpoints if not. An endpoint at an infinite value, such as
+    /// `f64::INFINITY`, becomes an unbounded side, so `[0, ∞]` becomes `[0, ∞)`. A lower endpoint
+    /// at positive infinity, an upper endpoint at negative infinity, or an exact infinite value
+    /// leaves no finite values, and is an error.
+    pub fn validate(self) -> Result<Self, InvalidBounds<T>> {
+        let (lower, upper) = match self {
+            Bounds::Exact(x) if is_nan(&x) || x.infinity().is_some() => {
+                return Err(InvalidBounds {
+                    lower: Some(Bound::inclusive(x.clone())),
+                    upper: Some(Bound::inclusive(x)),
+                });
             }
-            (a, b @ &Bounds::Exact(_)) => -b.compare_to(a),
-            (&Bounds::Exact(ref a), &Bounds::Range(ref x, ref y)) => {
-                debug_assert_bounds_order(x, y);
-                if let &Some(ref x) = x {
-                    match x.bound_type {
-                        Inclusive => {
-                            if a < &x.value {
-                                return Comparison::Less;
-                            }
-                        }
-                        Exclusive => {
-                            if a <= &x.value {
-                                return Comparison::Less;
-                            }
-                        }
-                    }
+            Bounds::Exact(x) => return Ok(Bounds::Exact(x)),
+            Bounds::Range(lower, upper) => (lower, upper),
+        };
+        let nan = [&lower, &upper]
+            .into_iter()
+            .flatten()
+            .any(|x| is_nan(&x.value));
+        let at = |bound: &Option<Bound<T>>, infinity: Ordering| {
+            bound
+                .as_ref()
+                .is_some_and(|x| x.value.infinity() == Some(infinity))
+        };
+        if nan
+            || at(&lower, Ordering::Greater)
+            || at(&upper, Ordering::Less)
+            || junction(&upper, &lower) != Junction::Overlap
+        {
+            return Err(InvalidBounds { lower, upper });
+        }
+        // the bounds hold a value, so dropping an infinite endpoint can only add more
+        Ok(Bounds::Range(
+            lower.filter(|a| a.value.infinity().is_none()),
+            upper.filter(|b| b.value.infinity().is_none()),
+        ))
+    }
+}
+
+impl<T: PartialOrd + Clone> Bounds<T> {
+    /// Returns the region shared by both bounds, or `None` if they don't overlap.
+    pub fn intersection(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        let low = match (self.lower(), other.lower()) {
+            (None, x) | (x, None) => x,
+            (Some(a), Some(x)) => Some(Bound::lower_bound_max(a, x)),
+        };
+        let high = match (self.upper(), other.upper()) {
+            (None, y) | (y, None) => y,
+            (Some(b), Some(y)) => Some(Bound::upper_bound_min(b, y)),
+        };
+        Bounds::nonempty(low, high).map(Bounds::cloned)
+    }
+
+    /// Returns every value not covered by these bounds.
+    pub fn complement(&self) -> Split<T> {
+        if self.is_empty() {
+            return Split::One(Bounds::Range(None, None));
+        }
+        let below = self
+            .lower()
+            .map(|a| Bounds::Range(None, Some(a.cloned().flip())));
+        let above = self
+            .upper()
+            .map(|b| Bounds::Range(Some(b.cloned().flip()), None));
+        Split::from_pieces(below, above)
+    }
+
+    /// Returns the values covered by `self` but not by `other`.
+    pub fn difference(&self, other: &Bounds<T>) -> Split<T> {
+        match other.complement() {
+            Split::Empty => Split::Empty,
+            Split::One(a) => Split::from_pieces(self.intersection(&a), None),
+            Split::Two(a, b) => Split::from_pieces(self.intersection(&a), self.intersection(&b)),
+        }
+    }
+
+    /// Returns bounds for the smaller of a value from `self` and a value from `other`.
+    pub fn min(self, other: Self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_empty() {
+            return other;
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (&self, &other) {
+            return Bounds::Exact(if x < a { x } else { a }.clone());
+        }
+        let low = self.lower_endpoint().lower_min(other.lower_endpoint());
+        let high = self.upper_endpoint().upper_min(other.upper_endpoint());
+        Bounds::Range(low.into_bound(), high.into_bound())
+            .collapse()
+            .cloned()
+    }
+
+    /// Returns bounds for the larger of a value from `self` and a value from `other`.
+    pub fn max(self, other: Self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if other.is_empty() {
+            return other;
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (&self, &other) {
+            return Bounds::Exact(if x > a { x } else { a }.clone());
+        }
+        let low = self.lower_endpoint().lower_max(other.lower_endpoint());
+        let high = self.upper_endpoint().upper_max(other.upper_endpoint());
+        Bounds::Range(low.into_bound(), high.into_bound())
+            .collapse()
+            .cloned()
+    }
+}
+
+impl<T: Neg<Output = T> + PartialOrd + Zero> Bounds<T> {
+    /// Returns bounds for the absolute value of every value in `self`.
+    pub fn abs(self) -> Bounds<T> {
+        if self.is_empty() {
+            return self;
+        }
+        let signs = SignBounds::from_bounds(&self);
+        match self {
+            Bounds::Exact(x) => Bounds::Exact(if x < T::zero() { -x } else { x }),
+            Bounds::Range(a, b) => {
+                if !signs.below_zero {
+                    Bounds::Range(a, b)
+                } else if !signs.above_zero {
+                    -Bounds::Range(a, b)
+                } else {
+                    let high = (-Endpoint::lower(a)).upper_max(Endpoint::upper(b));
+                    Bounds::Range(Some(Bound::inclusive(T::zero())), high.into_bound())
                 }
+            }
+        }
+    }
+}
 
-                if let &Some(ref y) = y {
-                    match y.bound_type {
-                        Inclusive => {
-                            if a > &y.value {
-                                return Comparison::Greater;
-                            }
-                        }
-                        Exclusive => {
-                            if a >= &y.value {
-                                return Comparison::Greater;
-                            }
-                        }
+impl<T: PartialOrd> Bounds<T> {
+    /// Builds bounds from a lower and upper endpoint, collapsing a single inclusive point into
+    /// `Exact`. Returns `None` if no value lies between the endpoints.
+    pub(crate) fn nonempty(low: Option<Bound<T>>, high: Option<Bound<T>>) -> Option<Self> {
+        if let (Some(a), Some(b)) = (&low, &high) {
+            match compare(&a.value, &b.value) {
+                Ordering::Greater => return None,
+                Ordering::Equal => {
+                    if a.bound_type == Exclusive || b.bound_type == Exclusive {
+                        return None;
                     }
+                    return high.map(|b| Bounds::Exact(b.value));
                 }
+                Ordering::Less => {}
+            }
+        }
+        Some(Bounds::Range(low, high))
+    }
 
-                return Comparison::Intersects;
+    /// Returns true if no value lies between the endpoints, such as `(1, 1)` or `[3, 2]`.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Bounds::Exact(_) => false,
+            // a range ending at `b` must share a value with one starting at `a`
+            Bounds::Range(a, b) => junction(b, a) != Junction::Overlap,
+        }
+    }
+
+    /// Collapses a single-point range such as `[1, 1]` into `Exact`.
+    pub(crate) fn collapse(self) -> Self {
+        match self {
+            Bounds::Range(Some(a), Some(b))
+                if a.bound_type == Inclusive && b.bound_type == Inclusive && a.value == b.value =>
+            {
+                Bounds::Exact(a.value)
             }
+            x => x,
+        }
+    }
+
+    /// Collapses a single-point range into `Exact`, or returns `None` if the range is empty.
+    pub fn normalize(self) -> Option<Self> {
+        match self {
+            Bounds::Exact(x) => Some(Bounds::Exact(x)),
+            Bounds::Range(a, b) => Bounds::nonempty(a, b),
         }
     }
 }
 
-#[inline(always)]
-fn debug_assert_bounds_order<T: PartialOrd>(x: &Option<Bound<T>>, y: &Option<Bound<T>>) {
-    debug_assert!(
-        match (x, y) {
-            (Some(ref x), Some(ref y)) => x.value <= y.value,
-            _ => true,
-        },
-        "invalid range: start must be less than end"
-    );
+impl<T: Clone> Bounds<&T> {
+    pub(crate) fn cloned(self) -> Bounds<T> {
+        match self {
+            Bounds::Exact(x) => Bounds::Exact(x.clone()),
+            Bounds::Range(a, b) => Bounds::Range(a.map(Bound::cloned), b.map(Bound::cloned)),
+        }
+    }
 }
@@ -0,0 +1,135 @@
+use crate::bound::{cmp_lower_bounds, junction, Bound, Junction};
+use crate::bounds::Bounds;
+use std::fmt;
+use std::fmt::Debug;
+use std::iter::FromIterator;
+
+/// A union of bounds, stored as a sorted list of disjoint, non-adjacent pieces.
+#[derive(Eq, PartialEq, Clone)]
+pub struct BoundsSet<T> {
+    bounds: Vec<Bounds<T>>,
+}
+
+impl<T: Debug> fmt::Debug for BoundsSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(&self.bounds).finish()
+    }
+}
+
+impl<T> Default for BoundsSet<T> {
+    fn default() -> Self {
+        BoundsSet::new()
+    }
+}
+
+impl<T> BoundsSet<T> {
+    pub fn new() -> Self {
+        BoundsSet { bounds: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// The number of disjoint pieces in the set.
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Bounds<T>> {
+        self.bounds.iter()
+    }
+}
+
+impl<T: PartialOrd> BoundsSet<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        self.bounds.iter().any(|x| x.contains(value))
+    }
+
+    pub fn insert(&mut self, bounds: Bounds<T>) {
+        let mut pieces = std::mem::take(&mut self.bounds);
+        pieces.push(bounds);
+        *self = pieces.into_iter().collect();
+    }
+}
+
+impl<T: PartialOrd + Clone> BoundsSet<T> {
+    pub fn union(&self, other: &BoundsSet<T>) -> BoundsSet<T> {
+        self.bounds.iter().chain(&other.bounds).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &BoundsSet<T>) -> BoundsSet<T> {
+        self.bounds
+            .iter()
+            .flat_map(|a| other.bounds.iter().filter_map(move |x| a.intersection(x)))
+            .collect()
+    }
+
+    pub fn difference(&self, other: &BoundsSet<T>) -> BoundsSet<T> {
+        self.intersection(&other.complement())
+    }
+
+    pub fn complement(&self) -> BoundsSet<T> {
+        let mut gaps = Vec::with_capacity(self.bounds.len() + 1);
+        let mut low: Option<Bound<T>> = None;
+        for bounds in &self.bounds {
+            if let Some(high) = bounds.lower() {
+                gaps.extend(Bounds::nonempty(low, Some(high.cloned().flip())));
+            }
+            match bounds.upper() {
+                Some(upper) => low = Some(upper.cloned().flip()),
+                None => return BoundsSet { bounds: gaps },
+            }
+        }
+        gaps.extend(Bounds::nonempty(low, None));
+        BoundsSet { bounds: gaps }
+    }
+}
+
+impl<T: PartialOrd> FromIterator<Bounds<T>> for BoundsSet<T> {
+    fn from_iter<I: IntoIterator<Item = Bounds<T>>>(iter: I) -> Self {
+        let mut pieces: Vec<Bounds<T>> = iter.into_iter().filter_map(Bounds::normalize).collect();
+        pieces.sort_by(|a, b| cmp_lower_bounds(&a.lower(), &b.lower()));
+
+        let mut bounds: Vec<Bounds<T>> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match bounds.pop() {
+                Some(last) => {
+                    if junction(&last.upper(), &piece.lower()) != Junction::Gap {
+                        let merged = last.merge(piece);
+                        bounds.push(merged);
+                    } else {
+                        bounds.push(last);
+                        bounds.push(piece);
+                    }
+                }
+                None => bounds.push(piece),
+            }
+        }
+        BoundsSet { bounds }
+    }
+}
+
+impl<T: PartialOrd> From<Bounds<T>> for BoundsSet<T> {
+    fn from(bounds: Bounds<T>) -> Self {
+        Some(bounds).into_iter().collect()
+    }
+}
+
+impl<T> IntoIterator for BoundsSet<T> {
+    type Item = Bounds<T>;
+    type IntoIter = std::vec::IntoIter<Bounds<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bounds.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundsSet<T> {
+    type Item = &'a Bounds<T>;
+    type IntoIter = std::slice::Iter<'a, Bounds<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.bounds.iter()
+    }
+}
@@ -0,0 +1,185 @@
+use crate::bound::Bound;
+use crate::bounds::{div_ranges, enclose, mul_ranges, Bounds};
+use crate::error::DivError;
+use crate::rounded::RoundedDiv;
+use crate::sign_bounds::SignBounds;
+use num::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+use std::cell::Cell;
+
+/// Combines two optional endpoints with `op`, returning `None` if `op` overflows, and `Some(None)`
+/// if either endpoint is unbounded.
+fn combine_endpoints<T, F: FnOnce(&T, &T) -> Option<T>>(
+    a: Option<Bound<&T>>,
+    b: Option<Bound<&T>>,
+    op: F,
+) -> Option<Option<Bound<T>>> {
+    match (a, b) {
+        (Some(a), Some(b)) => op(a.value, b.value).map(|value| {
+            Some(Bound {
+                bound_type: a.bound_type.combine(&b.bound_type),
+                value,
+            })
+        }),
+        _ => Some(None),
+    }
+}
+
+/// Arithmetic for types with a limited range, such as the primitive integers, where the plain
+/// operators would panic or wrap around. The `checked_*` methods return `None` if any endpoint of
+/// the result overflows. The `widening_*` methods instead leave the side of the result that
+/// overflowed unbounded, so the result still contains every possible value.
+impl<T: CheckedAdd + Clone + PartialOrd> Bounds<T> {
+    pub fn checked_add(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        if other.is_empty() {
+            return Some(other.clone());
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            return a.checked_add(x).map(Bounds::Exact);
+        }
+        Some(Bounds::Range(
+            combine_endpoints(self.lower(), other.lower(), T::checked_add)?,
+            combine_endpoints(self.upper(), other.upper(), T::checked_add)?,
+        ))
+    }
+
+    pub fn widening_add(&self, other: &Bounds<T>) -> Bounds<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            if let Some(sum) = a.checked_add(x) {
+                return Bounds::Exact(sum);
+            }
+        }
+        Bounds::Range(
+            combine_endpoints(self.lower(), other.lower(), T::checked_add).flatten(),
+            combine_endpoints(self.upper(), other.upper(), T::checked_add).flatten(),
+        )
+    }
+}
+
+impl<T: CheckedSub + Clone + PartialOrd> Bounds<T> {
+    /// Subtracts, returning `None` if any endpoint of the difference overflows, such as when an
+    /// unsigned difference could be negative.
+    pub fn checked_sub(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        if other.is_empty() {
+            return Some(other.clone());
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            return a.checked_sub(x).map(Bounds::Exact);
+        }
+        Some(Bounds::Range(
+            combine_endpoints(self.lower(), other.upper(), T::checked_sub)?,
+            combine_endpoints(self.upper(), other.lower(), T::checked_sub)?,
+        ))
+    }
+
+    pub fn widening_sub(&self, other: &Bounds<T>) -> Bounds<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        if let (Bounds::Exact(a), Bounds::Exact(x)) = (self, other) {
+            if let Some(difference) = a.checked_sub(x) {
+                return Bounds::Exact(difference);
+            }
+        }
+        Bounds::Range(
+            combine_endpoints(self.lower(), other.upper(), T::checked_sub).flatten(),
+            combine_endpoints(self.upper(), other.lower(), T::checked_sub).flatten(),
+        )
+    }
+}
+
+impl<T: CheckedMul + Clone + PartialOrd + Zero> Bounds<T> {
+    pub fn checked_mul(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        let overflowed = Cell::new(false);
+        let product = self.mul_with(other, |a, b| {
+            let product = a.checked_mul(b);
+            overflowed.set(overflowed.get() || product.is_none());
+            product
+        });
+        if overflowed.get() {
+            None
+        } else {
+            Some(product)
+        }
+    }
+
+    pub fn widening_mul(&self, other: &Bounds<T>) -> Bounds<T> {
+        self.mul_with(other, T::checked_mul)
+    }
+
+    fn mul_with<F: Fn(&T, &T) -> Option<T>>(&self, other: &Bounds<T>, product: F) -> Bounds<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if other.is_empty() {
+            return other.clone();
+        }
+        match (self, other) {
+            (Bounds::Exact(a), Bounds::Exact(x)) => match product(a, x) {
+                Some(value) => Bounds::Exact(value),
+                None => Bounds::Range(None, None),
+            },
+            (Bounds::Exact(a), _) | (_, Bounds::Exact(a)) if a.is_zero() => {
+                Bounds::Exact(a.clone())
+            }
+            _ => mul_ranges(self, other, |a, b| product(a, b).map(|x| (x.clone(), x))),
+        }
+    }
+}
+
+impl<T: CheckedDiv + RoundedDiv + Clone + PartialOrd + Zero> Bounds<T> {
+    /// Divides, explaining why the quotient is undefined instead of returning `None` like `/`.
+    /// Quotients are rounded outwards like `/`, so integer bounds `[1, 3] / 2` are `[0, 2]`.
+    pub fn checked_div(&self, other: &Bounds<T>) -> Result<Bounds<T>, DivError<T>> {
+        if let Bounds::Exact(x) = other.clone().collapse() {
+            if x.is_zero() {
+                return Err(DivError::DivisorIsZero);
+            }
+        }
+        if self.is_empty() {
+            return Ok(self.clone());
+        }
+        if other.is_empty() {
+            return Ok(other.clone());
+        }
+        let overflowed = Cell::new(false);
+        let quotient = |a: &T, b: &T| {
+            let quotient = a.checked_div(b);
+            overflowed.set(overflowed.get() || quotient.is_none());
+            quotient.map(|_| (a.div_down(b), a.div_up(b)))
+        };
+        let result = match (self, other) {
+            (Bounds::Exact(a), Bounds::Exact(x)) => {
+                quotient(a, x).map(|(low, high)| enclose(low, high))
+            }
+            (Bounds::Exact(a), _) if a.is_zero() => {
+                if SignBounds::from_bounds(other).zero {
+                    None
+                } else {
+                    Some(self.clone())
+                }
+            }
+            _ => div_ranges(self, other, quotient),
+        };
+        if overflowed.get() {
+            return Err(DivError::Overflow);
+        }
+        result.ok_or_else(|| DivError::DivisorContainsZero {
+            divisor: other.clone(),
+        })
+    }
+}
@@ -0,0 +1,457 @@
+use crate::bound::Bound;
+use crate::bounds::{enclose, Bounds};
+use crate::infinity::Infinity;
+use crate::rounded::{RoundedMul, RoundedOps};
+use num::{BigInt, BigRational, FromPrimitive, One, Signed, ToPrimitive, Zero};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+/// Elementary functions computed as an enclosure: two values that the exact result lies between,
+/// returned as `(low, high)`. Exact results, such as `sqrt(4)`, are returned as the same value
+/// twice.
+///
+/// `BigRational` computes each enclosure to within `precision`, which must be positive. Floats
+/// can't get closer than their own spacing, so they treat a finer precision as one step between
+/// floats.
+pub trait Elementary: Sized {
+    /// Encloses the square root of a value that isn't negative.
+    fn sqrt_enclosure(&self, precision: &Self) -> (Self, Self);
+    /// Encloses `e` raised to the value.
+    fn exp_enclosure(&self, precision: &Self) -> (Self, Self);
+    /// Encloses the natural logarithm of a positive value.
+    fn ln_enclosure(&self, precision: &Self) -> (Self, Self);
+    /// Encloses the base 2 logarithm of a positive value.
+    fn log2_enclosure(&self, precision: &Self) -> (Self, Self);
+}
+
+/// Roughly the base 2 logarithm of a positive value, rounded towards zero.
+fn magnitude(x: &BigRational) -> u64 {
+    x.numer().bits().saturating_sub(x.denom().bits())
+}
+
+/// The spacing of the grid that working values are rounded to, given the working precision.
+fn grid(bits: u64) -> BigRational {
+    BigRational::new(BigInt::one(), BigInt::one() << bits)
+}
+
+/// Rounds down to a multiple of `grid(bits)`, which keeps working values from growing without
+/// bound.
+fn floor_to(x: &BigRational, bits: u64) -> BigRational {
+    let scale = BigInt::one() << bits;
+    BigRational::new((x * &scale).floor().to_integer(), scale)
+}
+
+/// Rounds up to a multiple of `grid(bits)`.
+fn ceil_to(x: &BigRational, bits: u64) -> BigRational {
+    let scale = BigInt::one() << bits;
+    BigRational::new((x * &scale).ceil().to_integer(), scale)
+}
+
+/// Calls `enclose` with more and more bits of working precision, until the enclosure it returns
+/// is at most `precision` wide.
+fn refine<F>(precision: &BigRational, enclose: F) -> (BigRational, BigRational)
+where
+    F: Fn(u64) -> (BigRational, BigRational),
+{
+    assert!(precision.is_positive(), "precision must be positive");
+    let mut bits = magnitude(&precision.recip()) + 16;
+    loop {
+        let (low, high) = enclose(bits);
+        let width = &high - &low;
+        if width <= *precision {
+            return (low, high);
+        }
+        bits += magnitude(&(width / precision)) + 8;
+    }
+}
+
+/// Encloses `exp(y)` for `0 <= y <= 1/2` with its Taylor series. Each term is at most half the
+/// one before, so the terms left out add up to no more than the last one added.
+fn exp_series(y: &BigRational, bits: u64) -> (BigRational, BigRational) {
+    let limit = grid(bits);
+    let (mut low, mut high) = (BigRational::one(), BigRational::one());
+    let (mut term_low, mut term_high) = (BigRational::one(), BigRational::one());
+    let mut i = BigInt::one();
+    while term_high > limit {
+        term_low = floor_to(&(term_low * y / &i), bits);
+        term_high = ceil_to(&(term_high * y / &i), bits);
+        low += &term_low;
+        high += &term_high;
+        i += 1;
+    }
+    high += term_high;
+    (low, high)
+}
+
+/// Encloses `atanh(z)` for `0 <= z <= 1/3` with its Taylor series. Each term is at most a ninth
+/// of the one before, so the terms left out add up to less than the last one added.
+fn atanh_series(z: &BigRational, bits: u64) -> (BigRational, BigRational) {
+    let limit = grid(bits);
+    let z_squared = z * z;
+    let (mut power_low, mut power_high) = (z.clone(), z.clone());
+    let (mut low, mut high) = (BigRational::zero(), BigRational::zero());
+    let mut i = BigInt::one();
+    loop {
+        let term_low = floor_to(&(&power_low / &i), bits);
+        let term_high = ceil_to(&(&power_high / &i), bits);
+        low += term_low;
+        high += &term_high;
+        if term_high <= limit {
+            high += term_high;
+            return (low, high);
+        }
+        power_low = floor_to(&(power_low * &z_squared), bits);
+        power_high = ceil_to(&(power_high * &z_squared), bits);
+        i += 2;
+    }
+}
+
+/// Encloses `ln(2) = 2 atanh(1/3)`.
+fn ln_2(bits: u64) -> (BigRational, BigRational) {
+    let (low, high) = atanh_series(&BigRational::new(1.into(), 3.into()), bits);
+    (low * BigInt::from(2), high * BigInt::from(2))
+}
+
+fn exp(x: &BigRational, bits: u64) -> (BigRational, BigRational) {
+    if x.is_zero() {
+        return (BigRational::one(), BigRational::one());
+    }
+    if x.is_negative() {
+        let (low, high) = exp(&-x, bits);
+        return (floor_to(&high.recip(), bits), ceil_to(&low.recip(), bits));
+    }
+    // exp(x) = exp(x / 2^k)^(2^k), with x / 2^k small enough for the series
+    let half = BigRational::new(1.into(), 2.into());
+    let mut y = x.clone();
+    let mut squarings = 0;
+    while y > half {
+        y /= BigInt::from(2);
+        squarings += 1;
+    }
+    let (mut low, mut high) = exp_series(&y, bits);
+    for _ in 0..squarings {
+        low = floor_to(&(&low * &low), bits);
+        high = ceil_to(&(&high * &high), bits);
+    }
+    (low, high)
+}
+
+fn ln(x: &BigRational, bits: u64) -> (BigRational, BigRational) {
+    if x.is_one() {
+        return (BigRational::zero(), BigRational::zero());
+    }
+    if *x < BigRational::one() {
+        let (low, high) = ln(&x.recip(), bits);
+        return (-high, -low);
+    }
+    // ln(x) = e ln(2) + ln(m), for x = m 2^e with 1 <= m < 2
+    let two = BigInt::from(2);
+    let mut m = x.clone();
+    let mut e = BigInt::zero();
+    while m >= BigRational::from(two.clone()) {
+        m /= &two;
+        e += 1;
+    }
+    // ln(m) = 2 atanh((m - 1) / (m + 1))
+    let z = (&m - BigInt::one()) / (&m + BigInt::one());
+    let (m_low, m_high) = atanh_series(&z, bits);
+    let (two_low, two_high) = ln_2(bits);
+    (two_low * &e + m_low * &two, two_high * &e + m_high * &two)
+}
+
+/// The exponent of `x` if it is a power of two.
+fn exact_log2(x: &BigRational) -> Option<BigInt> {
+    let is_power = |n: &BigInt| n.is_positive() && n.trailing_zeros() == Some(n.bits() - 1);
+    if x.denom().is_one() && is_power(x.numer()) {
+        Some(BigInt::from(x.numer().bits() - 1))
+    } else if x.numer().is_one() && is_power(x.denom()) {
+        Some(-BigInt::from(x.denom().bits() - 1))
+    } else {
+        None
+    }
+}
+
+fn log2(x: &BigRational, bits: u64) -> (BigRational, BigRational) {
+    let (ln_low, ln_high) = ln(x, bits);
+    let (two_low, two_high) = ln_2(bits);
+    // divide by the end of ln(2) that moves each end of ln(x) outwards
+    let low = if ln_low.is_negative() {
+        ln_low / &two_low
+    } else {
+        ln_low / &two_high
+    };
+    let high = if ln_high.is_negative() {
+        ln_high / &two_high
+    } else {
+        ln_high / &two_low
+    };
+    (floor_to(&low, bits), ceil_to(&high, bits))
+}
+
+impl Elementary for BigRational {
+    fn sqrt_enclosure(&self, precision: &Self) -> (Self, Self) {
+        let (numer, denom) = (self.numer().sqrt(), self.denom().sqrt());
+        if &numer * &numer == *self.numer() && &denom * &denom == *self.denom() {
+            let root = BigRational::new(numer, denom);
+            return (root.clone(), root);
+        }
+        refine(precision, |bits| {
+            // s^2 <= x 4^bits < (s + 1)^2
+            let scaled = self * (BigInt::one() << (2 * bits));
+            let root = scaled.floor().to_integer().sqrt();
+            let scale = BigInt::one() << bits;
+            (
+                BigRational::new(root.clone(), scale.clone()),
+                BigRational::new(root + 1, scale),
+            )
+        })
+    }
+
+    fn exp_enclosure(&self, precision: &Self) -> (Self, Self) {
+        if self.is_zero() {
+            return (BigRational::one(), BigRational::one());
+        }
+        refine(precision, |bits| exp(self, bits))
+    }
+
+    fn ln_enclosure(&self, precision: &Self) -> (Self, Self) {
+        assert!(
+            self.is_positive(),
+            "logarithm of a value that isn't positive"
+        );
+        if self.is_one() {
+            return (BigRational::zero(), BigRational::zero());
+        }
+        refine(precision, |bits| ln(self, bits))
+    }
+
+    fn log2_enclosure(&self, precision: &Self) -> (Self, Self) {
+        assert!(
+            self.is_positive(),
+            "logarithm of a value that isn't positive"
+        );
+        if let Some(e) = exact_log2(self) {
+            return (BigRational::from(e.clone()), BigRational::from(e));
+        }
+        refine(precision, |bits| log2(self, bits))
+    }
+}
+
+/// The largest float no greater than `x`.
+fn to_f64_down(x: &BigRational) -> f64 {
+    let float = x.to_f64().unwrap_or(f64::NAN);
+    match BigRational::from_float(float) {
+        Some(exact) if exact <= *x => float,
+        _ => float.next_down(),
+    }
+}
+
+/// The smallest float no less than `x`.
+fn to_f64_up(x: &BigRational) -> f64 {
+    let float = x.to_f64().unwrap_or(f64::NAN);
+    match BigRational::from_float(float) {
+        Some(exact) if exact >= *x => float,
+        _ => float.next_up(),
+    }
+}
+
+/// Encloses a function of a float by enclosing it for the exact value of the float as a
+/// `BigRational`, then rounding the enclosure outwards to floats. The working precision is kept
+/// near the spacing of floats around `approximate`, a value close to the result, since anything
+/// finer would be lost in the rounding.
+fn through_rational(
+    x: f64,
+    precision: f64,
+    approximate: f64,
+    enclose: fn(&BigRational, &BigRational) -> (BigRational, BigRational),
+) -> (f64, f64) {
+    let Some(exact) = BigRational::from_float(x) else {
+        return (approximate, approximate);
+    };
+    let spacing = BigRational::from_float(approximate.abs() * 2f64.powi(-60));
+    let precision = [BigRational::from_float(precision), spacing]
+        .into_iter()
+        .flatten()
+        .fold(grid(1100), Ord::max);
+    let (low, high) = enclose(&exact, &precision);
+    (to_f64_down(&low), to_f64_up(&high))
+}
+
+impl Elementary for f64 {
+    /// The square root of a float is correctly rounded, so the exact root is at most one step
+    /// away from it, and `precision` isn't needed.
+    fn sqrt_enclosure(&self, _precision: &Self) -> (Self, Self) {
+        let root = self.sqrt();
+        let low = if root.mul_up(&root) <= *self {
+            root
+        } else {
+            root.next_down()
+        };
+        let high = if root.mul_down(&root) >= *self {
+            root
+        } else {
+            root.next_up()
+        };
+        (low, high)
+    }
+
+    fn exp_enclosure(&self, precision: &Self) -> (Self, Self) {
+        // beyond these, the result is above the largest float or below the smallest positive one
+        if *self > 709.79 {
+            return (f64::MAX, f64::INFINITY);
+        }
+        if *self < -745.2 {
+            return (0.0, f64::from_bits(1));
+        }
+        through_rational(*self, *precision, self.exp(), BigRational::exp_enclosure)
+    }
+
+    /// Values that aren't positive give the same result as `f64::ln`, `-∞` or `NaN`.
+    fn ln_enclosure(&self, precision: &Self) -> (Self, Self) {
+        if *self <= 0.0 {
+            return (self.ln(), self.ln());
+        }
+        through_rational(*self, *precision, self.ln(), BigRational::ln_enclosure)
+    }
+
+    fn log2_enclosure(&self, precision: &Self) -> (Self, Self) {
+        if *self <= 0.0 {
+            return (self.log2(), self.log2());
+        }
+        through_rational(*self, *precision, self.log2(), BigRational::log2_enclosure)
+    }
+}
+
+/// Applies an increasing function to the bounds, using `f` to enclose it at each endpoint.
+/// Endpoints keep their type. An unbounded lower side becomes `limit`, the bound that the
+/// function approaches towards negative infinity, and an unbounded upper side stays unbounded.
+/// An enclosure reaching an infinite value, like `exp` overflowing a float, leaves that side
+/// unbounded too.
+fn map_increasing<T: PartialOrd + Infinity, F: Fn(&T) -> (T, T)>(
+    bounds: Bounds<T>,
+    f: F,
+    limit: Option<Bound<T>>,
+) -> Bounds<T> {
+    let result = match bounds {
+        Bounds::Exact(x) => {
+            let (low, high) = f(&x);
+            enclose(low, high)
+        }
+        Bounds::Range(a, b) => Bounds::Range(
+            a.map_or(limit, |a| Some(a.map(|x| f(&x).0))),
+            b.map(|b| b.map(|x| f(&x).1)),
+        ),
+    };
+    let bounded = |bound: &Bound<T>, side| bound.value.infinity() != Some(side);
+    match result {
+        Bounds::Range(a, b) => Bounds::Range(
+            a.filter(|a| bounded(a, Ordering::Less)),
+            b.filter(|b| bounded(b, Ordering::Greater)),
+        ),
+        x => x,
+    }
+}
+
+/// These compute each endpoint of the result to within `precision`, using [`Elementary`]. Values
+/// outside the domain of a function are ignored, and the result is `None` if every value is
+/// outside it.
+impl<T: Elementary + Infinity + Clone + PartialOrd + Zero> Bounds<T> {
+    /// The square roots of the values that aren't negative, so `[-1, 4]` gives `[0, 2]`.
+    pub fn sqrt(&self, precision: &T) -> Option<Bounds<T>> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        let domain = self.intersection(&Bounds::Range(Some(Bound::inclusive(T::zero())), None))?;
+        Some(map_increasing(
+            domain,
+            |x| x.sqrt_enclosure(precision),
+            None,
+        ))
+    }
+
+    /// `e` raised to each value. This is defined everywhere, so it never returns `None`.
+    pub fn exp(&self, precision: &T) -> Bounds<T> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        map_increasing(
+            self.clone(),
+            |x| x.exp_enclosure(precision),
+            Some(Bound::exclusive(T::zero())),
+        )
+    }
+
+    /// The natural logarithms of the positive values. Bounds reaching down to zero have no lower
+    /// bound on their logarithm.
+    pub fn ln(&self, precision: &T) -> Option<Bounds<T>> {
+        self.log_with(|x| x.ln_enclosure(precision))
+    }
+
+    /// The base 2 logarithms of the positive values, like [`Bounds::ln`].
+    pub fn log2(&self, precision: &T) -> Option<Bounds<T>> {
+        self.log_with(|x| x.log2_enclosure(precision))
+    }
+
+    fn log_with<F: Fn(&T) -> (T, T)>(&self, f: F) -> Option<Bounds<T>> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        let domain = self.intersection(&Bounds::Range(Some(Bound::exclusive(T::zero())), None))?;
+        let domain = match domain {
+            Bounds::Range(Some(a), b) if a.value.is_zero() => Bounds::Range(None, b),
+            x => x,
+        };
+        Some(map_increasing(domain, f, None))
+    }
+}
+
+impl<T> Bounds<T>
+where
+    T: Elementary
+        + Infinity
+        + RoundedOps
+        + Clone
+        + PartialOrd
+        + Zero
+        + One
+        + Debug
+        + ToPrimitive
+        + FromPrimitive,
+{
+    /// Raises each value in the bounds to each power in `exp`.
+    ///
+    /// An exact integer exponent uses [`Bounds::pow`], which is exact for exact types and defined
+    /// for every base, so `[-2, 3]^2` is `[0, 9]`. A negative integer exponent then divides 1 by
+    /// the power, and returns `None` if the base may be zero, like division.
+    ///
+    /// Any other exponent is computed as `exp(exp * ln(self))`, with each step to within
+    /// `precision`. This is only defined for positive bases, and for a base of zero with a
+    /// positive exponent, which gives zero. Negative bases are ignored like in [`Bounds::ln`],
+    /// and the result is `None` if no base is left.
+    pub fn powf(&self, exp: &Bounds<T>, precision: &T) -> Option<Bounds<T>> {
+        if self.is_empty() {
+            return Some(self.clone());
+        }
+        if exp.is_empty() {
+            return Some(exp.clone());
+        }
+        if let Bounds::Exact(y) = exp {
+            let integer = y.to_i32().filter(|n| T::from_i32(*n).as_ref() == Some(y));
+            if let Some(n) = integer {
+                let power = self.clone().pow(n.unsigned_abs());
+                return if n >= 0 {
+                    Some(power)
+                } else {
+                    Bounds::Exact(T::one()) / power
+                };
+            }
+        }
+        let positive = self.ln(precision).map(|ln| (ln * exp).exp(precision));
+        let positive_exp = Bounds::Range(Some(Bound::exclusive(T::zero())), None);
+        if self.contains(&T::zero()) && exp.intersects(&positive_exp) {
+            let zero = Bounds::Exact(T::zero());
+            return Some(positive.map_or(zero.clone(), |x| x.merge(zero)));
+        }
+        positive
+    }
+}
@@ -0,0 +1,236 @@
+use crate::bound::{compare, Bound, BoundType};
+use num::Zero;
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// One end of a range, with explicit infinities in place of the `None` used by
+/// [`Bounds::Range`](crate::Bounds::Range), so that the side of an unbounded end is never
+/// ambiguous.
+///
+/// Arithmetic follows the extended reals. Results that the extended reals leave undefined, such
+/// as `∞ - ∞`, are `None`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Endpoint<T> {
+    NegInfinity,
+    Finite(Bound<T>),
+    PosInfinity,
+}
+
+impl<T> Endpoint<T> {
+    /// The start of a range, where `None` is negative infinity.
+    pub fn lower(bound: Option<Bound<T>>) -> Endpoint<T> {
+        bound.map_or(Endpoint::NegInfinity, Endpoint::Finite)
+    }
+
+    /// The end of a range, where `None` is positive infinity.
+    pub fn upper(bound: Option<Bound<T>>) -> Endpoint<T> {
+        bound.map_or(Endpoint::PosInfinity, Endpoint::Finite)
+    }
+
+    /// The finite bound, or `None` for either infinity.
+    pub fn into_bound(self) -> Option<Bound<T>> {
+        match self {
+            Endpoint::Finite(bound) => Some(bound),
+            _ => None,
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Endpoint::Finite(_))
+    }
+
+    pub fn as_ref(&self) -> Endpoint<&T> {
+        match self {
+            Endpoint::NegInfinity => Endpoint::NegInfinity,
+            Endpoint::Finite(bound) => Endpoint::Finite(bound.as_ref()),
+            Endpoint::PosInfinity => Endpoint::PosInfinity,
+        }
+    }
+
+    pub(crate) fn infinity(sign: Ordering) -> Endpoint<T> {
+        if sign == Ordering::Less {
+            Endpoint::NegInfinity
+        } else {
+            Endpoint::PosInfinity
+        }
+    }
+}
+
+impl<T: Clone> Endpoint<&T> {
+    pub fn cloned(self) -> Endpoint<T> {
+        match self {
+            Endpoint::NegInfinity => Endpoint::NegInfinity,
+            Endpoint::Finite(bound) => Endpoint::Finite(bound.cloned()),
+            Endpoint::PosInfinity => Endpoint::PosInfinity,
+        }
+    }
+}
+
+impl<T: Zero + PartialOrd> Endpoint<&T> {
+    /// Whether the endpoint is below, at or above zero.
+    pub(crate) fn sign(&self) -> Ordering {
+        match self {
+            Endpoint::NegInfinity => Ordering::Less,
+            Endpoint::Finite(bound) => compare(bound.value, &T::zero()),
+            Endpoint::PosInfinity => Ordering::Greater,
+        }
+    }
+}
+
+/// The ends of a union or intersection of ranges, picked from the ends of the ranges.
+impl<T: PartialOrd> Endpoint<T> {
+    /// The earlier of two lower endpoints, which starts their union.
+    pub(crate) fn lower_min(self, other: Self) -> Endpoint<T> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => {
+                Endpoint::Finite(Bound::lower_bound_min(a, b))
+            }
+            (Endpoint::NegInfinity, _) | (_, Endpoint::NegInfinity) => Endpoint::NegInfinity,
+            (Endpoint::PosInfinity, x) | (x, Endpoint::PosInfinity) => x,
+        }
+    }
+
+    /// The later of two lower endpoints, which starts their intersection.
+    pub(crate) fn lower_max(self, other: Self) -> Endpoint<T> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => {
+                Endpoint::Finite(Bound::lower_bound_max(a, b))
+            }
+            (Endpoint::PosInfinity, _) | (_, Endpoint::PosInfinity) => Endpoint::PosInfinity,
+            (Endpoint::NegInfinity, x) | (x, Endpoint::NegInfinity) => x,
+        }
+    }
+
+    /// The earlier of two upper endpoints, which ends their intersection.
+    pub(crate) fn upper_min(self, other: Self) -> Endpoint<T> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => {
+                Endpoint::Finite(Bound::upper_bound_min(a, b))
+            }
+            (Endpoint::NegInfinity, _) | (_, Endpoint::NegInfinity) => Endpoint::NegInfinity,
+            (Endpoint::PosInfinity, x) | (x, Endpoint::PosInfinity) => x,
+        }
+    }
+
+    /// The later of two upper endpoints, which ends their union.
+    pub(crate) fn upper_max(self, other: Self) -> Endpoint<T> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => {
+                Endpoint::Finite(Bound::upper_bound_max(a, b))
+            }
+            (Endpoint::PosInfinity, _) | (_, Endpoint::PosInfinity) => Endpoint::PosInfinity,
+            (Endpoint::NegInfinity, x) | (x, Endpoint::NegInfinity) => x,
+        }
+    }
+}
+
+/// The sign of a product of values with the signs `a` and `b`.
+pub(crate) fn mul_signs(a: Ordering, b: Ordering) -> Ordering {
+    match (a, b) {
+        (Ordering::Equal, _) | (_, Ordering::Equal) => Ordering::Equal,
+        (a, b) if a == b => Ordering::Greater,
+        _ => Ordering::Less,
+    }
+}
+
+impl<T> From<Bound<T>> for Endpoint<T> {
+    fn from(bound: Bound<T>) -> Self {
+        Endpoint::Finite(bound)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Endpoint<T> {
+    type Output = Endpoint<T>;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Endpoint::NegInfinity => Endpoint::PosInfinity,
+            Endpoint::Finite(bound) => Endpoint::Finite(-bound),
+            Endpoint::PosInfinity => Endpoint::NegInfinity,
+        }
+    }
+}
+
+impl<T> Endpoint<T> {
+    /// Adds two endpoints, using `add` for two finite values. The endpoints may be borrowed, with
+    /// `add` returning an owned sum.
+    pub(crate) fn add_with<U, F: FnOnce(T, T) -> U>(
+        self,
+        other: Self,
+        add: F,
+    ) -> Option<Endpoint<U>> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => Some(Endpoint::Finite(a.combine(b, add))),
+            (Endpoint::NegInfinity, Endpoint::PosInfinity)
+            | (Endpoint::PosInfinity, Endpoint::NegInfinity) => None,
+            (Endpoint::NegInfinity, _) | (_, Endpoint::NegInfinity) => Some(Endpoint::NegInfinity),
+            (Endpoint::PosInfinity, _) | (_, Endpoint::PosInfinity) => Some(Endpoint::PosInfinity),
+        }
+    }
+
+    /// Subtracts `other`, using `sub` for two finite values, like [`Endpoint::add_with`].
+    pub(crate) fn sub_with<U, F: FnOnce(T, T) -> U>(
+        self,
+        other: Self,
+        sub: F,
+    ) -> Option<Endpoint<U>> {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => Some(Endpoint::Finite(a.combine(b, sub))),
+            (Endpoint::NegInfinity, Endpoint::NegInfinity)
+            | (Endpoint::PosInfinity, Endpoint::PosInfinity) => None,
+            (Endpoint::NegInfinity, _) | (_, Endpoint::PosInfinity) => Some(Endpoint::NegInfinity),
+            (Endpoint::PosInfinity, _) | (_, Endpoint::NegInfinity) => Some(Endpoint::PosInfinity),
+        }
+    }
+}
+
+impl<T: Zero + PartialOrd> Endpoint<T> {
+    /// Multiplies two endpoints when at least one of them is infinite, following the same rules
+    /// as `*`. Returns `None` for two finite endpoints too, since their product needs `T: Mul`.
+    pub(crate) fn mul_unbounded(self, other: Self) -> Option<Endpoint<T>> {
+        let sign = mul_signs(self.as_ref().sign(), other.as_ref().sign());
+        match (self, other) {
+            (Endpoint::Finite(_), Endpoint::Finite(_)) => None,
+            (Endpoint::Finite(zero), _) | (_, Endpoint::Finite(zero)) if zero.value.is_zero() => {
+                match zero.bound_type {
+                    BoundType::Inclusive => Some(Endpoint::Finite(zero)),
+                    BoundType::Exclusive => None,
+                }
+            }
+            _ => Some(Endpoint::infinity(sign)),
+        }
+    }
+}
+
+impl<T: Add<T, Output = T>> Add for Endpoint<T> {
+    type Output = Option<Endpoint<T>>;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add_with(other, Add::add)
+    }
+}
+
+impl<T: Sub<T, Output = T>> Sub for Endpoint<T> {
+    type Output = Option<Endpoint<T>>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_with(other, Sub::sub)
+    }
+}
+
+/// Zero times infinity is undefined in the extended reals. As an endpoint, though, an inclusive
+/// zero is a value the range actually reaches, and multiplying it by anything gives exactly zero,
+/// so that product is an inclusive zero. Only an exclusive zero times infinity is `None`.
+impl<T: Mul<T, Output = T> + Zero + PartialOrd> Mul for Endpoint<T> {
+    type Output = Option<Endpoint<T>>;
+
+    fn mul(self, other: Self) -> Self::Output {
+        match (self, other) {
+            (Endpoint::Finite(a), Endpoint::Finite(b)) => Some(Endpoint::Finite(Bound {
+                bound_type: a.as_ref().product_type(&b.as_ref()),
+                value: a.value * b.value,
+            })),
+            (a, b) => a.mul_unbounded(b),
+        }
+    }
+}
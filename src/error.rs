@@ -0,0 +1,58 @@
+use crate::bound::{is_nan, Bound};
+use crate::bounds::{fmt_range, Bounds};
+use std::error::Error;
+use std::fmt;
+use std::fmt::Debug;
+
+/// Returned when a range is created from endpoints that don't have any values between them,
+/// such as a start that is above the end or a start at positive infinity, or from an endpoint
+/// that is `NaN`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct InvalidBounds<T> {
+    pub lower: Option<Bound<T>>,
+    pub upper: Option<Bound<T>>,
+}
+
+impl<T: Debug + PartialOrd> fmt::Display for InvalidBounds<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid range ")?;
+        fmt_range(&self.lower, &self.upper, f)?;
+        let nan = [&self.lower, &self.upper]
+            .into_iter()
+            .flatten()
+            .any(|x| is_nan(&x.value));
+        if nan {
+            write!(f, ": endpoints must not be NaN")
+        } else {
+            write!(f, ": start must be less than end")
+        }
+    }
+}
+
+impl<T: Debug + PartialOrd> Error for InvalidBounds<T> {}
+
+/// Returned by [`Bounds::checked_div`](crate::Bounds::checked_div) when the quotient is undefined,
+/// or can't be represented.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum DivError<T> {
+    /// The divisor is exactly zero.
+    DivisorIsZero,
+    /// The divisor may be zero, but could also be other values.
+    DivisorContainsZero { divisor: Bounds<T> },
+    /// A quotient doesn't fit in the type, such as `i32::MIN / -1`.
+    Overflow,
+}
+
+impl<T: Debug> fmt::Display for DivError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DivError::DivisorIsZero => write!(f, "division by zero"),
+            DivError::DivisorContainsZero { divisor } => {
+                write!(f, "division by {:?}, which contains zero", divisor)
+            }
+            DivError::Overflow => write!(f, "overflow in division"),
+        }
+    }
+}
+
+impl<T: Debug> Error for DivError<T> {}
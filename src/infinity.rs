@@ -0,0 +1,56 @@
+use num::rational::Ratio;
+use num::{BigInt, BigUint};
+use std::cmp::Ordering;
+
+/// Types that may have values beyond every finite value, such as `f64::INFINITY`. When bounds
+/// are validated with [`Bounds::validate`](crate::Bounds::validate), an endpoint at an infinite
+/// value becomes an unbounded side.
+///
+/// The method defaults to `None`, so a type without infinite values only needs an empty
+/// `impl Infinity for MyType {}` to be validated.
+pub trait Infinity {
+    /// `Less` for negative infinity, `Greater` for positive infinity, and `None` for any other
+    /// value.
+    fn infinity(&self) -> Option<Ordering> {
+        None
+    }
+}
+
+macro_rules! impl_finite {
+    ($($t:ty),*) => {
+        $(
+            impl Infinity for $t {}
+        )*
+    };
+}
+
+impl_finite!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_finite!(BigInt, BigUint, char, str, String);
+
+impl<T> Infinity for Ratio<T> {}
+
+impl<T: Infinity + ?Sized> Infinity for &T {
+    fn infinity(&self) -> Option<Ordering> {
+        (**self).infinity()
+    }
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Infinity for $t {
+                fn infinity(&self) -> Option<Ordering> {
+                    if *self == <$t>::INFINITY {
+                        Some(Ordering::Greater)
+                    } else if *self == <$t>::NEG_INFINITY {
+                        Some(Ordering::Less)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
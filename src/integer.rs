@@ -0,0 +1,195 @@
+use crate::bound::{Bound, BoundType};
+use crate::bounds::{hull, Bounds};
+use crate::endpoint::{mul_signs, Endpoint};
+use num::{CheckedAdd, CheckedSub, Integer};
+use std::cmp::Ordering;
+
+/// Bounds that contain no integers.
+fn empty<T: Integer>() -> Bounds<T> {
+    Bounds::Range(
+        Some(Bound::exclusive(T::zero())),
+        Some(Bound::exclusive(T::zero())),
+    )
+}
+
+fn contains_zero<T: Integer>(low: &Option<T>, high: &Option<T>) -> bool {
+    low.as_ref().is_none_or(|a| *a <= T::zero()) && high.as_ref().is_none_or(|b| *b >= T::zero())
+}
+
+/// These treat the bounds as the set of integers they contain, so an exclusive endpoint is
+/// replaced by the next integer inside the range, and every result is a sound enclosure of the
+/// exact integer results. They return `None` if the divisor may be zero.
+impl<T: Integer + CheckedAdd + CheckedSub + Clone> Bounds<T> {
+    /// Divides, rounding each quotient towards negative infinity.
+    pub fn div_floor(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        self.integer_div(other, T::div_floor, |quotient, _| {
+            if quotient == Ordering::Less {
+                T::zero() - T::one()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    /// Divides, rounding each quotient towards zero.
+    pub fn div_trunc(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        self.integer_div(other, |x, y| x.clone() / y.clone(), |_, _| T::zero())
+    }
+
+    /// Divides so that the remainder is never negative, like the primitive `div_euclid`.
+    pub fn div_euclid(&self, other: &Bounds<T>) -> Option<Bounds<T>> {
+        let div_euclid = |x: &T, y: &T| {
+            if *y > T::zero() {
+                x.div_floor(y)
+            } else {
+                x.div_ceil(y)
+            }
+        };
+        self.integer_div(other, div_euclid, |quotient, divisor| {
+            match (quotient, divisor) {
+                (Ordering::Less, Ordering::Greater) => T::zero() - T::one(),
+                (Ordering::Greater, Ordering::Less) => T::one(),
+                _ => T::zero(),
+            }
+        })
+    }
+
+    /// Returns the non-negative remainder of dividing by `modulus`, like the primitive
+    /// `rem_euclid`. If the bounds cross a multiple of the modulus, every remainder is possible,
+    /// and the result falls back to `[0, |m| - 1]`, using the largest modulus. A remainder is
+    /// never above a value that isn't negative, so bounds that aren't negative also cap it at
+    /// their largest value.
+    pub fn rem_euclid(&self, modulus: &Bounds<T>) -> Option<Bounds<T>> {
+        let (m_low, m_high) = match modulus.integer_range() {
+            Some(range) => range,
+            None => return Some(empty()),
+        };
+        if contains_zero(&m_low, &m_high) {
+            return None;
+        }
+        let (x_low, x_high) = match self.integer_range() {
+            Some(range) => range,
+            None => return Some(empty()),
+        };
+        let abs = |x: T| if x < T::zero() { T::zero() - x } else { x };
+        let (m_min, m_max) = match (m_low, m_high) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (abs(a), abs(b));
+                if a <= b {
+                    (a, Some(b))
+                } else {
+                    (b, Some(a))
+                }
+            }
+            (Some(a), None) => (a, None),
+            (None, Some(b)) => (abs(b), None),
+            (None, None) => return None,
+        };
+        if let (Some(a), Some(b)) = (&x_low, &x_high) {
+            if m_max.as_ref() == Some(&m_min) {
+                if a.div_floor(&m_min) == b.div_floor(&m_min) {
+                    let low = Bound::inclusive(a.mod_floor(&m_min));
+                    let high = Bound::inclusive(b.mod_floor(&m_min));
+                    return Some(Bounds::Range(Some(low), Some(high)).collapse());
+                }
+            } else if *a >= T::zero() && *b < m_min {
+                let range = Bounds::Range(
+                    Some(Bound::inclusive(a.clone())),
+                    x_high.map(Bound::inclusive),
+                );
+                return Some(range.collapse());
+            }
+        }
+        let mut high = m_max.map(|m| m - T::one());
+        if let (Some(a), Some(b)) = (&x_low, x_high) {
+            if *a >= T::zero() && high.as_ref().is_none_or(|m| b < *m) {
+                high = Some(b);
+            }
+        }
+        Some(
+            Bounds::Range(
+                Some(Bound::inclusive(T::zero())),
+                high.map(Bound::inclusive),
+            )
+            .collapse(),
+        )
+    }
+
+    /// The smallest and largest integers within the bounds, with `None` for an unbounded side.
+    /// Returns `None` if the bounds contain no integers, including when stepping past an
+    /// exclusive endpoint overflows, since no integer of the type lies beyond it.
+    fn integer_range(&self) -> Option<(Option<T>, Option<T>)> {
+        let low = match self.lower() {
+            Some(a) => Some(match a.bound_type {
+                BoundType::Inclusive => a.value.clone(),
+                BoundType::Exclusive => a.value.checked_add(&T::one())?,
+            }),
+            None => None,
+        };
+        let high = match self.upper() {
+            Some(b) => Some(match b.bound_type {
+                BoundType::Inclusive => b.value.clone(),
+                BoundType::Exclusive => b.value.checked_sub(&T::one())?,
+            }),
+            None => None,
+        };
+        if let (Some(a), Some(b)) = (&low, &high) {
+            if a > b {
+                return None;
+            }
+        }
+        Some((low, high))
+    }
+
+    /// Divides using `op`, which must be monotonic in the real quotient `x / y`. Since the
+    /// extremes of `x / y` over a box lie at its corners, so do the extremes of `op`. When the
+    /// divisor is unbounded, the quotient approaches zero, and `limit` gives the value `op`
+    /// settles on, given the sign of the quotient and the divisor.
+    fn integer_div<F, L>(&self, other: &Bounds<T>, op: F, limit: L) -> Option<Bounds<T>>
+    where
+        F: Fn(&T, &T) -> T,
+        L: Fn(Ordering, Ordering) -> T,
+    {
+        let (y_low, y_high) = match other.integer_range() {
+            Some(range) => range,
+            None => return Some(empty()),
+        };
+        if contains_zero(&y_low, &y_high) {
+            return None;
+        }
+        let (x_low, x_high) = match self.integer_range() {
+            Some(range) => range,
+            None => return Some(empty()),
+        };
+        let xs = [
+            Endpoint::lower(x_low.map(Bound::inclusive)),
+            Endpoint::upper(x_high.map(Bound::inclusive)),
+        ];
+        let ys = [
+            Endpoint::lower(y_low.map(Bound::inclusive)),
+            Endpoint::upper(y_high.map(Bound::inclusive)),
+        ];
+
+        let mut candidates = Vec::with_capacity(8);
+        for x in &xs {
+            for y in &ys {
+                let y_sign = y.as_ref().sign();
+                let sign = mul_signs(x.as_ref().sign(), y_sign);
+                let settled = || Endpoint::Finite(Bound::inclusive(limit(sign, y_sign)));
+                match (x, y) {
+                    (Endpoint::Finite(x), Endpoint::Finite(y)) => {
+                        candidates.push(Endpoint::Finite(Bound::inclusive(op(&x.value, &y.value))))
+                    }
+                    (Endpoint::Finite(_), _) => candidates.push(settled()),
+                    (_, Endpoint::Finite(_)) => candidates.push(Endpoint::infinity(sign)),
+                    _ => {
+                        // both grow without bound, so the quotient takes every value of its sign
+                        candidates.push(Endpoint::infinity(sign));
+                        candidates.push(settled());
+                    }
+                }
+            }
+        }
+        Some(hull(candidates).collapse())
+    }
+}
@@ -1,11 +1,32 @@
 mod bound;
 mod bounds;
+mod bounds_set;
+mod checked;
 mod comparison;
+mod elementary;
+mod endpoint;
+mod error;
+mod infinity;
+mod integer;
+mod relation;
+mod round;
+mod rounded;
 pub(crate) mod sign_bounds;
+mod split;
+mod trig;
 
 pub use crate::bound::{Bound, BoundType};
 pub use crate::bounds::Bounds;
+pub use crate::bounds_set::BoundsSet;
 pub use crate::comparison::Comparison;
+pub use crate::elementary::Elementary;
+pub use crate::endpoint::Endpoint;
+pub use crate::error::{DivError, InvalidBounds};
+pub use crate::infinity::Infinity;
+pub use crate::relation::Relation;
+pub use crate::round::Round;
+pub use crate::rounded::{ExactOps, RoundedAdd, RoundedDiv, RoundedMul, RoundedOps, RoundedSub};
+pub use crate::split::Split;
 
 #[cfg(test)]
 mod test;
@@ -56,6 +77,54 @@ macro_rules! bounds {
     };
 }
 
+/// Like [`bounds!`], but returns a `Result`, with an [`InvalidBounds`] error if the range is
+/// empty.
+#[macro_export]
+macro_rules! try_bounds {
+    ($val:expr) => {
+        $crate::Bounds::Exact($val).validate()
+    };
+    ($a:expr,$b:expr) => {
+        $crate::Bounds::try_range(
+            Some($crate::Bound::inclusive($a)),
+            Some($crate::Bound::inclusive($b)),
+        )
+    };
+    (~$a:expr,$b:expr) => {
+        $crate::Bounds::try_range(
+            Some($crate::Bound::exclusive($a)),
+            Some($crate::Bound::inclusive($b)),
+        )
+    };
+    ($a:expr,~$b:expr) => {
+        $crate::Bounds::try_range(
+            Some($crate::Bound::inclusive($a)),
+            Some($crate::Bound::exclusive($b)),
+        )
+    };
+    (~$a:expr,~$b:expr) => {
+        $crate::Bounds::try_range(
+            Some($crate::Bound::exclusive($a)),
+            Some($crate::Bound::exclusive($b)),
+        )
+    };
+    (,$b:expr) => {
+        $crate::Bounds::try_range(None, Some($crate::Bound::inclusive($b)))
+    };
+    (,~$b:expr) => {
+        $crate::Bounds::try_range(None, Some($crate::Bound::exclusive($b)))
+    };
+    ($a:expr,) => {
+        $crate::Bounds::try_range(Some($crate::Bound::inclusive($a)), None)
+    };
+    (~$a:expr,) => {
+        $crate::Bounds::try_range(Some($crate::Bound::exclusive($a)), None)
+    };
+    (,) => {
+        $crate::Bounds::try_range(None, None)
+    };
+}
+
 #[test]
 fn test_macro() {
     assert_eq!(bounds!(3), Bounds::Exact(3));
@@ -80,3 +149,24 @@ fn test_macro() {
     assert_eq!(bounds!(1,), Bounds::Range(Some(Bound::inclusive(1)), None));
     assert_eq!(bounds!(,), Bounds::<u32>::Range(None, None));
 }
+
+#[test]
+fn test_try_macro() {
+    assert_eq!(try_bounds!(3), Ok(Bounds::Exact(3)));
+    assert_eq!(try_bounds!(1, 2), Ok(bounds!(1, 2)));
+    assert_eq!(try_bounds!(1, 1), Ok(bounds!(1, 1)));
+    assert_eq!(try_bounds!(~1, 2), Ok(bounds!(~1, 2)));
+    assert_eq!(try_bounds!(,~2), Ok(bounds!(,~2)));
+    assert_eq!(try_bounds!(~1,), Ok(bounds!(~1,)));
+    assert_eq!(try_bounds!(,), Ok(Bounds::<u32>::Range(None, None)));
+    assert_eq!(
+        try_bounds!(2, 1),
+        Err(InvalidBounds {
+            lower: Some(Bound::inclusive(2)),
+            upper: Some(Bound::inclusive(1)),
+        })
+    );
+    assert!(try_bounds!(~1, 1).is_err());
+    assert!(try_bounds!(1, ~1).is_err());
+    assert!(try_bounds!(~1, ~1).is_err());
+}
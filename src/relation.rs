@@ -0,0 +1,67 @@
+use crate::comparison::Comparison;
+use std::ops::Neg;
+
+/// The 13 relations of Allen's interval algebra, describing how `self` is positioned relative
+/// to `other`. Bounds are treated as sets of values, so whether an endpoint is inclusive decides
+/// between relations like `Meets` and `Overlaps`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Relation {
+    /// `self` ends before `other` starts, with a gap between them.
+    Before,
+    /// `self` ends exactly where `other` starts, with no gap and no shared values.
+    Meets,
+    /// `self` starts first and ends inside `other`.
+    Overlaps,
+    /// Both start together, and `self` ends first.
+    Starts,
+    /// `self` lies strictly inside `other`.
+    During,
+    /// Both end together, and `self` starts last.
+    Finishes,
+    /// Both cover exactly the same values.
+    Equals,
+    /// Both end together, and `self` starts first.
+    FinishedBy,
+    /// `other` lies strictly inside `self`.
+    Contains,
+    /// Both start together, and `self` ends last.
+    StartedBy,
+    /// `self` starts inside `other` and ends last.
+    OverlappedBy,
+    /// `self` starts exactly where `other` ends, with no gap and no shared values.
+    MetBy,
+    /// `self` starts after `other` ends, with a gap between them.
+    After,
+}
+
+impl Neg for Relation {
+    type Output = Relation;
+
+    fn neg(self) -> Self::Output {
+        match self {
+            Relation::Before => Relation::After,
+            Relation::Meets => Relation::MetBy,
+            Relation::Overlaps => Relation::OverlappedBy,
+            Relation::Starts => Relation::StartedBy,
+            Relation::During => Relation::Contains,
+            Relation::Finishes => Relation::FinishedBy,
+            Relation::Equals => Relation::Equals,
+            Relation::FinishedBy => Relation::Finishes,
+            Relation::Contains => Relation::During,
+            Relation::StartedBy => Relation::Starts,
+            Relation::OverlappedBy => Relation::Overlaps,
+            Relation::MetBy => Relation::Meets,
+            Relation::After => Relation::Before,
+        }
+    }
+}
+
+impl From<Relation> for Comparison {
+    fn from(relation: Relation) -> Self {
+        match relation {
+            Relation::Before | Relation::Meets => Comparison::Less,
+            Relation::After | Relation::MetBy => Comparison::Greater,
+            _ => Comparison::Intersects,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::bound::{Bound, BoundType};
+use crate::bounds::Bounds;
+use crate::rounded::RoundedOps;
+use num::rational::Ratio;
+use num::{Integer, One, Zero};
+
+/// Rounding to an integer, keeping the same type. `round` rounds half-way values away from zero.
+pub trait Round {
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+    fn round(&self) -> Self;
+    fn trunc(&self) -> Self;
+}
+
+impl<T: Clone + Integer> Round for Ratio<T> {
+    fn floor(&self) -> Self {
+        Ratio::floor(self)
+    }
+    fn ceil(&self) -> Self {
+        Ratio::ceil(self)
+    }
+    fn round(&self) -> Self {
+        Ratio::round(self)
+    }
+    fn trunc(&self) -> Self {
+        Ratio::trunc(self)
+    }
+}
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl Round for $t {
+                fn floor(&self) -> Self {
+                    <$t>::floor(*self)
+                }
+                fn ceil(&self) -> Self {
+                    <$t>::ceil(*self)
+                }
+                fn round(&self) -> Self {
+                    <$t>::round(*self)
+                }
+                fn trunc(&self) -> Self {
+                    <$t>::trunc(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Which values of the rounding function are reached just inside an exclusive endpoint.
+enum Continuity {
+    /// Rounding a value just above the endpoint gives the same as rounding the endpoint, like
+    /// `floor`.
+    Right,
+    /// Rounding a value just below the endpoint gives the same as rounding the endpoint, like
+    /// `ceil`.
+    Left,
+}
+
+/// These round every value in the bounds to an integer, and return the smallest bounds holding
+/// the results, so `[1.2, 3.7).floor()` is `[1, 3]`. An exclusive endpoint at a value where the
+/// rounding jumps to the next integer isn't rounded like the endpoint itself, so `[1, 4).floor()`
+/// is `[1, 3]` too.
+impl<T: Round + RoundedOps + Clone + PartialOrd + Zero + One> Bounds<T> {
+    pub fn floor(&self) -> Bounds<T> {
+        self.round_with(T::floor, |_| Continuity::Right, is_integer)
+    }
+
+    pub fn ceil(&self) -> Bounds<T> {
+        self.round_with(T::ceil, |_| Continuity::Left, is_integer)
+    }
+
+    /// Rounds half-way values away from zero, like `f64::round`.
+    pub fn round(&self) -> Bounds<T> {
+        let half_integer = |x: &T| is_integer(&x.add_down(x)) && !is_integer(x);
+        self.round_with(T::round, toward_zero, half_integer)
+    }
+
+    /// Rounds towards zero.
+    pub fn trunc(&self) -> Bounds<T> {
+        // every value between -1 and 1 truncates to zero, so there is no step at zero
+        let step = |x: &T| is_integer(x) && !x.is_zero();
+        self.round_with(T::trunc, toward_zero, step)
+    }
+
+    /// Rounds the bounds with `f`, a nondecreasing function that steps up by one at each value
+    /// where `is_step` is true. `continuity` tells which side of such a value has the same result
+    /// as the value itself.
+    fn round_with<F, C, S>(&self, f: F, continuity: C, is_step: S) -> Bounds<T>
+    where
+        F: Fn(&T) -> T,
+        C: Fn(&T) -> Continuity,
+        S: Fn(&T) -> bool,
+    {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let lower = self.lower().map(|a| {
+            let value = f(a.value);
+            let stepped = a.bound_type == BoundType::Exclusive
+                && matches!(continuity(a.value), Continuity::Left)
+                && is_step(a.value);
+            Bound::inclusive(if stepped {
+                value.add_down(&T::one())
+            } else {
+                value
+            })
+        });
+        let upper = self.upper().map(|b| {
+            let value = f(b.value);
+            let stepped = b.bound_type == BoundType::Exclusive
+                && matches!(continuity(b.value), Continuity::Right)
+                && is_step(b.value);
+            Bound::inclusive(if stepped {
+                value.sub_up(&T::one())
+            } else {
+                value
+            })
+        });
+        Bounds::Range(lower, upper).collapse()
+    }
+}
+
+fn is_integer<T: Round + PartialOrd>(x: &T) -> bool {
+    x.floor() == *x
+}
+
+/// `trunc` and `round` work like `floor` above zero, and like `ceil` below it.
+fn toward_zero<T: PartialOrd + Zero>(x: &T) -> Continuity {
+    if *x >= T::zero() {
+        Continuity::Right
+    } else {
+        Continuity::Left
+    }
+}
@@ -0,0 +1,271 @@
+use num::rational::Ratio;
+use num::{BigInt, BigUint, Float, Integer};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Arithmetic that rounds in a known direction. The `_down` methods return a value no greater
+/// than the exact result, and the `_up` methods a value no less than it. The arithmetic operators
+/// on [`Bounds`](crate::Bounds) round the lower endpoint down and the upper endpoint up, so the
+/// result contains the exact result, even when `T` can't represent it.
+///
+/// Each operation has its own trait, and each operator on `Bounds` only needs the one it uses,
+/// such as [`RoundedAdd`] for `+`. This trait is implemented for every type with all four.
+///
+/// `f32` and `f64` step an inexact result to the neighbouring float. `Ratio`, such as
+/// `BigRational`, and types marked with [`ExactOps`] return the exact result from both
+/// directions. The integers are exact for `+`, `-` and `*`, and round quotients down with
+/// `div_floor` and up with `div_ceil`. The primitive integers still overflow like their operators
+/// do, panicking in debug builds and wrapping in release builds, so their results only contain
+/// the exact result if nothing overflows. Use [`Bounds::checked_add`](crate::Bounds::checked_add)
+/// and the other `checked_*` and `widening_*` methods for integers that may overflow.
+pub trait RoundedOps: RoundedAdd + RoundedSub + RoundedMul + RoundedDiv {}
+
+impl<T: RoundedAdd + RoundedSub + RoundedMul + RoundedDiv> RoundedOps for T {}
+
+pub trait RoundedAdd: Sized {
+    fn add_down(&self, other: &Self) -> Self;
+    fn add_up(&self, other: &Self) -> Self;
+}
+
+pub trait RoundedSub: Sized {
+    fn sub_down(&self, other: &Self) -> Self;
+    fn sub_up(&self, other: &Self) -> Self;
+}
+
+pub trait RoundedMul: Sized {
+    fn mul_down(&self, other: &Self) -> Self;
+    fn mul_up(&self, other: &Self) -> Self;
+}
+
+pub trait RoundedDiv: Sized {
+    fn div_down(&self, other: &Self) -> Self;
+    fn div_up(&self, other: &Self) -> Self;
+}
+
+/// Marks a type whose `+`, `-`, `*` and `/` return exact results, such as a rational or decimal
+/// type with unlimited precision. This implements each rounded trait the type has the operator
+/// for, returning the exact result from both directions, so `Bounds` of the type get the
+/// matching operators without implementing the rounded traits by hand.
+///
+/// Don't mark a type whose division truncates, like an integer type. Implement [`RoundedDiv`]
+/// with its floor and ceiling division instead.
+pub trait ExactOps {}
+
+impl<T: ExactOps + Add<T, Output = T> + Clone> RoundedAdd for T {
+    fn add_down(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
+    fn add_up(&self, other: &Self) -> Self {
+        self.clone() + other.clone()
+    }
+}
+
+impl<T: ExactOps + Sub<T, Output = T> + Clone> RoundedSub for T {
+    fn sub_down(&self, other: &Self) -> Self {
+        self.clone() - other.clone()
+    }
+    fn sub_up(&self, other: &Self) -> Self {
+        self.clone() - other.clone()
+    }
+}
+
+impl<T: ExactOps + Mul<T, Output = T> + Clone> RoundedMul for T {
+    fn mul_down(&self, other: &Self) -> Self {
+        self.clone() * other.clone()
+    }
+    fn mul_up(&self, other: &Self) -> Self {
+        self.clone() * other.clone()
+    }
+}
+
+impl<T: ExactOps + Div<T, Output = T> + Clone> RoundedDiv for T {
+    fn div_down(&self, other: &Self) -> Self {
+        self.clone() / other.clone()
+    }
+    fn div_up(&self, other: &Self) -> Self {
+        self.clone() / other.clone()
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl RoundedAdd for $t {
+                fn add_down(&self, other: &Self) -> Self {
+                    self + other
+                }
+                fn add_up(&self, other: &Self) -> Self {
+                    self + other
+                }
+            }
+
+            impl RoundedSub for $t {
+                fn sub_down(&self, other: &Self) -> Self {
+                    self - other
+                }
+                fn sub_up(&self, other: &Self) -> Self {
+                    self - other
+                }
+            }
+
+            impl RoundedMul for $t {
+                fn mul_down(&self, other: &Self) -> Self {
+                    self * other
+                }
+                fn mul_up(&self, other: &Self) -> Self {
+                    self * other
+                }
+            }
+
+            impl RoundedDiv for $t {
+                fn div_down(&self, other: &Self) -> Self {
+                    Integer::div_floor(self, other)
+                }
+                fn div_up(&self, other: &Self) -> Self {
+                    Integer::div_ceil(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, BigInt, BigUint);
+
+impl<T: Clone + Integer> RoundedAdd for Ratio<T> {
+    fn add_down(&self, other: &Self) -> Self {
+        self + other
+    }
+    fn add_up(&self, other: &Self) -> Self {
+        self + other
+    }
+}
+
+impl<T: Clone + Integer> RoundedSub for Ratio<T> {
+    fn sub_down(&self, other: &Self) -> Self {
+        self - other
+    }
+    fn sub_up(&self, other: &Self) -> Self {
+        self - other
+    }
+}
+
+impl<T: Clone + Integer> RoundedMul for Ratio<T> {
+    fn mul_down(&self, other: &Self) -> Self {
+        self * other
+    }
+    fn mul_up(&self, other: &Self) -> Self {
+        self * other
+    }
+}
+
+impl<T: Clone + Integer> RoundedDiv for Ratio<T> {
+    fn div_down(&self, other: &Self) -> Self {
+        self / other
+    }
+    fn div_up(&self, other: &Self) -> Self {
+        self / other
+    }
+}
+
+/// The error `exact - sum` of a rounded sum, which is itself exact (Knuth's TwoSum).
+fn sum_error<F: Float>(a: F, b: F, sum: F) -> F {
+    let b_rounded = sum - a;
+    let a_rounded = sum - b_rounded;
+    (a - a_rounded) + (b - b_rounded)
+}
+
+/// The error `exact - product` of a rounded product, found with a fused multiply-add. A product
+/// that underflowed may have lost bits even the error can't hold, so its error is unknown, and
+/// reported as `NaN`.
+fn product_error<F: Float>(a: F, b: F, product: F) -> F {
+    if product.abs() < F::min_positive_value() && !a.is_zero() && !b.is_zero() {
+        return F::nan();
+    }
+    a.mul_add(b, -product)
+}
+
+/// A value with the same sign as the error `exact - quotient` of a rounded quotient, found from
+/// the remainder `a - quotient * b`. As with [`product_error`], it is `NaN` after an underflow.
+fn quotient_error<F: Float>(a: F, b: F, quotient: F) -> F {
+    if quotient.abs() < F::min_positive_value() && !a.is_zero() {
+        return F::nan();
+    }
+    let remainder = (-quotient).mul_add(b, a);
+    if b < F::zero() {
+        -remainder
+    } else {
+        remainder
+    }
+}
+
+/// Keeps `value` if it is finite and no greater than the exact result, which is `value + error`,
+/// and otherwise steps down to the next float. An unknown (`NaN`) error always steps.
+fn round_down<F: Float>(value: F, error: F, next_down: fn(F) -> F) -> F {
+    if value.is_finite() && error >= F::zero() {
+        value
+    } else {
+        next_down(value)
+    }
+}
+
+/// Like [`round_down`], but keeps `value` only if it is no less than the exact result.
+fn round_up<F: Float>(value: F, error: F, next_up: fn(F) -> F) -> F {
+    if value.is_finite() && error <= F::zero() {
+        value
+    } else {
+        next_up(value)
+    }
+}
+
+/// Floats round to nearest, so each result is either exact or one step away from a value on the
+/// other side of the exact result. The error of the rounded result tells which, and only inexact
+/// results are moved to the neighbouring float. A result that overflowed to an infinity is also
+/// moved, since the exact result was finite.
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(
+            impl RoundedAdd for $t {
+                fn add_down(&self, other: &Self) -> Self {
+                    let sum = self + other;
+                    round_down(sum, sum_error(*self, *other, sum), <$t>::next_down)
+                }
+                fn add_up(&self, other: &Self) -> Self {
+                    let sum = self + other;
+                    round_up(sum, sum_error(*self, *other, sum), <$t>::next_up)
+                }
+            }
+
+            impl RoundedSub for $t {
+                fn sub_down(&self, other: &Self) -> Self {
+                    self.add_down(&-other)
+                }
+                fn sub_up(&self, other: &Self) -> Self {
+                    self.add_up(&-other)
+                }
+            }
+
+            impl RoundedMul for $t {
+                fn mul_down(&self, other: &Self) -> Self {
+                    let product = self * other;
+                    round_down(product, product_error(*self, *other, product), <$t>::next_down)
+                }
+                fn mul_up(&self, other: &Self) -> Self {
+                    let product = self * other;
+                    round_up(product, product_error(*self, *other, product), <$t>::next_up)
+                }
+            }
+
+            impl RoundedDiv for $t {
+                fn div_down(&self, other: &Self) -> Self {
+                    let quotient = self / other;
+                    round_down(quotient, quotient_error(*self, *other, quotient), <$t>::next_down)
+                }
+                fn div_up(&self, other: &Self) -> Self {
+                    let quotient = self / other;
+                    round_up(quotient, quotient_error(*self, *other, quotient), <$t>::next_up)
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
@@ -1,4 +1,6 @@
-use crate::bound::{Bound, BoundType};
+use crate::bound::{compare, BoundType};
+use crate::bounds::Bounds;
+use crate::endpoint::Endpoint;
 use num::Zero;
 use std::cmp::Ordering;
 
@@ -9,19 +11,19 @@ pub struct SignBounds {
 }
 
 impl SignBounds {
-    pub fn from_bounds<T: Ord + Zero>(a: &Option<Bound<T>>, b: &Option<Bound<T>>) -> SignBounds {
-        let left = a
-            .as_ref()
-            .map(Self::from_bound)
-            .unwrap_or(SignBounds::below_zero());
-        let right = b
-            .as_ref()
-            .map(Self::from_bound)
-            .unwrap_or(SignBounds::above_zero());
-        left.merge(&right)
+    pub fn from_bounds<T: PartialOrd + Zero>(bounds: &Bounds<T>) -> SignBounds {
+        Self::from_endpoints(bounds.lower_endpoint(), bounds.upper_endpoint())
     }
-    pub fn from_bound<T: Zero + Ord>(bound: &Bound<T>) -> SignBounds {
-        match bound.value.cmp(&T::zero()) {
+    pub fn from_endpoints<T: PartialOrd + Zero>(a: Endpoint<&T>, b: Endpoint<&T>) -> SignBounds {
+        Self::from_endpoint(a).merge(&Self::from_endpoint(b))
+    }
+    pub fn from_endpoint<T: Zero + PartialOrd>(endpoint: Endpoint<&T>) -> SignBounds {
+        let bound = match endpoint {
+            Endpoint::NegInfinity => return SignBounds::below_zero(),
+            Endpoint::Finite(bound) => bound,
+            Endpoint::PosInfinity => return SignBounds::above_zero(),
+        };
+        match compare(bound.value, &T::zero()) {
             Ordering::Equal => {
                 if bound.bound_type == BoundType::Inclusive {
                     SignBounds::zero()
@@ -0,0 +1,45 @@
+use crate::bounds::Bounds;
+use crate::bounds_set::BoundsSet;
+
+/// The result of an operation that can leave zero, one or two disjoint bounds.
+/// When there are two, they are in ascending order.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Split<T> {
+    Empty,
+    One(Bounds<T>),
+    Two(Bounds<T>, Bounds<T>),
+}
+
+impl<T> Split<T> {
+    pub(crate) fn from_pieces(a: Option<Bounds<T>>, b: Option<Bounds<T>>) -> Split<T> {
+        match (a, b) {
+            (None, None) => Split::Empty,
+            (Some(a), None) | (None, Some(a)) => Split::One(a),
+            (Some(a), Some(b)) => Split::Two(a, b),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Split::Empty)
+    }
+}
+
+impl<T> IntoIterator for Split<T> {
+    type Item = Bounds<T>;
+    type IntoIter = std::iter::Flatten<std::array::IntoIter<Option<Bounds<T>>, 2>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let pieces = match self {
+            Split::Empty => [None, None],
+            Split::One(a) => [Some(a), None],
+            Split::Two(a, b) => [Some(a), Some(b)],
+        };
+        pieces.into_iter().flatten()
+    }
+}
+
+impl<T: PartialOrd> From<Split<T>> for BoundsSet<T> {
+    fn from(split: Split<T>) -> Self {
+        split.into_iter().collect()
+    }
+}
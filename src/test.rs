@@ -1,7 +1,18 @@
+use crate::bound::{Bound, BoundType};
 use crate::bounds;
+use crate::try_bounds;
 
 use crate::bounds::Bounds;
+use crate::bounds_set::BoundsSet;
 use crate::comparison::Comparison;
+use crate::elementary::Elementary;
+use crate::endpoint::Endpoint;
+use crate::error::{DivError, InvalidBounds};
+use crate::relation::Relation;
+use crate::rounded::{ExactOps, RoundedAdd, RoundedDiv, RoundedMul};
+use crate::split::Split;
+use num::{BigRational, BigUint, Rational64};
+use std::ops::{Add, Sub};
 
 #[test]
 fn test_intersection() {
@@ -255,4 +266,1488 @@ fn test_div() {
     assert_eq!(bounds!(6,) / bounds!(-3, -2), Some(bounds!(,-2)));
     assert_eq!(bounds!(, -12) / bounds!(-3, -2), Some(bounds!(4,)));
     assert_eq!(bounds!(-6,) / bounds!(-3, -2), Some(bounds!(,3)));
+
+    // inexact integer quotients are rounded outwards
+    assert_eq!(bounds!(7) / bounds!(2), Some(bounds!(3, 4)));
+    assert_eq!(bounds!(-7) / 2, Some(bounds!(-4, -3)));
+    assert_eq!(bounds!(1, 3) / 2, Some(bounds!(0, 2)));
+    assert_eq!(bounds!(1, 3) / bounds!(-2), Some(bounds!(-2, 0)));
+    assert_eq!(bounds!(5u32, 7) / bounds!(2, 3), Some(bounds!(1, 4)));
+}
+
+#[test]
+fn test_intersection_bounds() {
+    assert_eq!(
+        bounds!(1, 3).intersection(&bounds!(2, 4)),
+        Some(bounds!(2, 3))
+    );
+    assert_eq!(
+        bounds!(1,~3).intersection(&bounds!(~2, 4)),
+        Some(bounds!(~2,~3))
+    );
+    assert_eq!(
+        bounds!(1, 3).intersection(&bounds!(~1, 3)),
+        Some(bounds!(~1, 3))
+    );
+    assert_eq!(
+        bounds!(1, 4).intersection(&bounds!(2, 3)),
+        Some(bounds!(2, 3))
+    );
+    assert_eq!(bounds!(1, 3).intersection(&bounds!(3, 5)), Some(bounds!(3)));
+    assert_eq!(bounds!(1,~3).intersection(&bounds!(3, 5)), None);
+    assert_eq!(bounds!(1, 3).intersection(&bounds!(~3, 5)), None);
+    assert_eq!(bounds!(1, 2).intersection(&bounds!(5, 6)), None);
+
+    assert_eq!(bounds!(2).intersection(&bounds!(2)), Some(bounds!(2)));
+    assert_eq!(bounds!(2).intersection(&bounds!(3)), None);
+    assert_eq!(bounds!(2).intersection(&bounds!(1, 3)), Some(bounds!(2)));
+    assert_eq!(bounds!(1, 3).intersection(&bounds!(1)), Some(bounds!(1)));
+    assert_eq!(bounds!(1).intersection(&bounds!(~1, 3)), None);
+    assert_eq!(bounds!(3).intersection(&bounds!(1, ~3)), None);
+
+    assert_eq!(bounds!(1,).intersection(&bounds!(,3)), Some(bounds!(1, 3)));
+    assert_eq!(bounds!(~1,).intersection(&bounds!(2,)), Some(bounds!(2,)));
+    assert_eq!(bounds!(,~1).intersection(&bounds!(,1)), Some(bounds!(,~1)));
+    assert_eq!(bounds!(,~1).intersection(&bounds!(1,)), None);
+    assert_eq!(bounds!(,).intersection(&bounds!(1,~3)), Some(bounds!(1,~3)));
+    assert_eq!(bounds!(,).intersection(&bounds!(4)), Some(bounds!(4)));
+    assert_eq!(
+        Bounds::<i32>::from(..).intersection(&bounds!(,)),
+        Some(bounds!(,))
+    );
+}
+
+#[test]
+fn test_bounds_set() {
+    let set: BoundsSet<i32> = vec![bounds!(5, 6), bounds!(1, 2)].into_iter().collect();
+    assert_eq!(
+        set.iter().cloned().collect::<Vec<_>>(),
+        vec![bounds!(1, 2), bounds!(5, 6)]
+    );
+    assert!(set.contains(&1));
+    assert!(set.contains(&6));
+    assert!(!set.contains(&3));
+
+    let set: BoundsSet<i32> = vec![bounds!(3, 5), bounds!(1,~3)].into_iter().collect();
+    assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![bounds!(1, 5)]);
+
+    let set: BoundsSet<i32> = vec![bounds!(1,~3), bounds!(~3, 5)].into_iter().collect();
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(&3));
+
+    let set: BoundsSet<i32> = vec![bounds!(1, 2), bounds!(2), bounds!(~2, 4), bounds!(~4, ~4)]
+        .into_iter()
+        .collect();
+    assert_eq!(set, BoundsSet::from(bounds!(1, 4)));
+
+    let set: BoundsSet<i32> = vec![bounds!(3, 3), bounds!(7,)].into_iter().collect();
+    assert_eq!(
+        set.into_iter().collect::<Vec<_>>(),
+        vec![bounds!(3), bounds!(7,)]
+    );
+}
+
+#[test]
+fn test_bounds_set_operations() {
+    let a: BoundsSet<i32> = vec![bounds!(1, 2), bounds!(5, 6)].into_iter().collect();
+    let b: BoundsSet<i32> = vec![bounds!(2,~5), bounds!(8,)].into_iter().collect();
+
+    assert_eq!(
+        a.union(&b).into_iter().collect::<Vec<_>>(),
+        vec![bounds!(1, 6), bounds!(8,)]
+    );
+    assert_eq!(
+        a.intersection(&b).into_iter().collect::<Vec<_>>(),
+        vec![bounds!(2)]
+    );
+    assert_eq!(
+        a.difference(&b).into_iter().collect::<Vec<_>>(),
+        vec![bounds!(1,~2), bounds!(5, 6)]
+    );
+    assert_eq!(
+        b.difference(&a).into_iter().collect::<Vec<_>>(),
+        vec![bounds!(~2,~5), bounds!(8,)]
+    );
+    assert_eq!(
+        a.complement().into_iter().collect::<Vec<_>>(),
+        vec![bounds!(,~1), bounds!(~2,~5), bounds!(~6,)]
+    );
+    assert_eq!(
+        b.complement().into_iter().collect::<Vec<_>>(),
+        vec![bounds!(,~2), bounds!(5,~8)]
+    );
+    assert_eq!(a.complement().complement(), a);
+
+    let holes: BoundsSet<i32> = vec![bounds!(,~3), bounds!(~3,)].into_iter().collect();
+    assert_eq!(
+        holes.complement().into_iter().collect::<Vec<_>>(),
+        vec![bounds!(3)]
+    );
+    assert_eq!(
+        BoundsSet::<i32>::new().complement(),
+        BoundsSet::from(bounds!(,))
+    );
+    assert!(BoundsSet::from(Bounds::<i32>::from(..))
+        .complement()
+        .is_empty());
+}
+
+#[test]
+fn test_complement() {
+    assert_eq!(
+        bounds!(1,~3).complement(),
+        Split::Two(bounds!(,~1), bounds!(3,))
+    );
+    assert_eq!(
+        bounds!(~1, 3).complement(),
+        Split::Two(bounds!(, 1), bounds!(~3,))
+    );
+    assert_eq!(
+        bounds!(2).complement(),
+        Split::Two(bounds!(,~2), bounds!(~2,))
+    );
+    assert_eq!(bounds!(1,).complement(), Split::One(bounds!(,~1)));
+    assert_eq!(bounds!(,~1).complement(), Split::One(bounds!(1,)));
+    assert_eq!(Bounds::<i32>::from(..).complement(), Split::Empty);
+}
+
+#[test]
+fn test_difference() {
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(2,~3)),
+        Split::Two(bounds!(1,~2), bounds!(3, 5))
+    );
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(3)),
+        Split::Two(bounds!(1,~3), bounds!(~3, 5))
+    );
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(3,)),
+        Split::One(bounds!(1,~3))
+    );
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(~0, 3)),
+        Split::One(bounds!(~3, 5))
+    );
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(~1, 5)),
+        Split::One(bounds!(1))
+    );
+    assert_eq!(
+        bounds!(1, 5).difference(&bounds!(6, 7)),
+        Split::One(bounds!(1, 5))
+    );
+    assert_eq!(bounds!(1, 5).difference(&bounds!(0, 7)), Split::Empty);
+    assert_eq!(bounds!(1, 5).difference(&bounds!(,)), Split::Empty);
+    assert_eq!(bounds!(3).difference(&bounds!(3, 4)), Split::Empty);
+    assert_eq!(
+        bounds!(3).difference(&bounds!(~3, 4)),
+        Split::One(bounds!(3))
+    );
+    assert_eq!(
+        bounds!(,).difference(&bounds!(1, 2)),
+        Split::Two(bounds!(,~1), bounds!(~2,))
+    );
+    assert_eq!(
+        bounds!(1, 5)
+            .difference(&bounds!(2, 3))
+            .into_iter()
+            .collect::<Vec<_>>(),
+        vec![bounds!(1,~2), bounds!(~3, 5)]
+    );
+}
+
+#[test]
+fn test_relation() {
+    assert_eq!(bounds!(1, 2).relation(&bounds!(3, 4)), Relation::Before);
+    assert_eq!(bounds!(1,~3).relation(&bounds!(~3, 4)), Relation::Before);
+    assert_eq!(bounds!(1,~3).relation(&bounds!(3, 4)), Relation::Meets);
+    assert_eq!(bounds!(1, 3).relation(&bounds!(~3, 4)), Relation::Meets);
+    assert_eq!(bounds!(1, 3).relation(&bounds!(3, 4)), Relation::Overlaps);
+    assert_eq!(bounds!(1, 3).relation(&bounds!(2, 4)), Relation::Overlaps);
+    assert_eq!(bounds!(1, 3).relation(&bounds!(1, 4)), Relation::Starts);
+    assert_eq!(bounds!(1,~4).relation(&bounds!(1, 4)), Relation::Starts);
+    assert_eq!(bounds!(2, 3).relation(&bounds!(1, 4)), Relation::During);
+    assert_eq!(bounds!(~1,~4).relation(&bounds!(1, 4)), Relation::During);
+    assert_eq!(bounds!(2, 4).relation(&bounds!(1, 4)), Relation::Finishes);
+    assert_eq!(bounds!(~1, 4).relation(&bounds!(1, 4)), Relation::Finishes);
+    assert_eq!(bounds!(1, 4).relation(&bounds!(1, 4)), Relation::Equals);
+    assert_eq!(bounds!(1, 4).relation(&bounds!(2, 4)), Relation::FinishedBy);
+    assert_eq!(bounds!(1, 4).relation(&bounds!(2, 3)), Relation::Contains);
+    assert_eq!(bounds!(1, 4).relation(&bounds!(1, 3)), Relation::StartedBy);
+    assert_eq!(
+        bounds!(2, 4).relation(&bounds!(1, 3)),
+        Relation::OverlappedBy
+    );
+    assert_eq!(bounds!(3, 4).relation(&bounds!(1,~3)), Relation::MetBy);
+    assert_eq!(bounds!(3, 4).relation(&bounds!(1, 2)), Relation::After);
+
+    assert_eq!(bounds!(3).relation(&bounds!(3)), Relation::Equals);
+    assert_eq!(bounds!(3).relation(&bounds!(3, 4)), Relation::Starts);
+    assert_eq!(bounds!(3).relation(&bounds!(~3, 4)), Relation::Meets);
+    assert_eq!(bounds!(4).relation(&bounds!(3, 4)), Relation::Finishes);
+    assert_eq!(bounds!(2).relation(&bounds!(1, 4)), Relation::During);
+    assert_eq!(bounds!(1, 4).relation(&bounds!(2)), Relation::Contains);
+
+    assert_eq!(bounds!(,3).relation(&bounds!(1, 4)), Relation::Overlaps);
+    assert_eq!(bounds!(,3).relation(&bounds!(,4)), Relation::Starts);
+    assert_eq!(bounds!(1,).relation(&bounds!(,)), Relation::Finishes);
+    assert_eq!(
+        Bounds::<i32>::from(..).relation(&bounds!(,)),
+        Relation::Equals
+    );
+    assert_eq!(bounds!(,).relation(&bounds!(1, 2)), Relation::Contains);
+    assert_eq!(bounds!(,~1).relation(&bounds!(1,)), Relation::Meets);
+    assert_eq!(bounds!(5,).relation(&bounds!(,4)), Relation::After);
+
+    assert_eq!(-Relation::Meets, Relation::MetBy);
+    assert_eq!(Comparison::from(Relation::Meets), Comparison::Less);
+    assert_eq!(Comparison::from(Relation::During), Comparison::Intersects);
+    assert_eq!(Comparison::from(Relation::After), Comparison::Greater);
+}
+
+#[test]
+fn test_empty() {
+    assert!(bounds!(~1,~1).is_empty());
+    assert!(bounds!(1,~1).is_empty());
+    assert!(bounds!(~1, 1).is_empty());
+    assert!(bounds!(3, 2).is_empty());
+    assert!(!bounds!(1, 1).is_empty());
+    assert!(!bounds!(1,~2).is_empty());
+    assert!(!bounds!(3).is_empty());
+    assert!(!bounds!(3,).is_empty());
+    assert!(!Bounds::<i32>::from(..).is_empty());
+
+    assert_eq!(bounds!(3, 2).normalize(), None);
+    assert_eq!(bounds!(~1,~1).normalize(), None);
+    assert_eq!(bounds!(1, 1).normalize(), Some(bounds!(1)));
+    assert_eq!(bounds!(1, 2).normalize(), Some(bounds!(1, 2)));
+
+    let empty = bounds!(3, 2);
+    assert!(!empty.intersects(&bounds!(0, 10)));
+    assert!(!bounds!(0, 10).intersects(&empty));
+    assert!(!empty.intersects(&bounds!(,)));
+    assert_eq!(empty.compare_to(&bounds!(0, 10)), Comparison::Less);
+    assert_eq!(bounds!(0, 10).compare_to(&empty), Comparison::Less);
+    assert_eq!(empty.relation(&bounds!(2, 3)), Relation::Before);
+    assert_eq!(bounds!(2, 3).relation(&empty), Relation::Before);
+    assert_eq!(empty.size(), Some(0));
+    assert_eq!(bounds!(~1,~1).size(), Some(0));
+    assert_eq!(empty.clone().merge(bounds!(5, 6)), bounds!(5, 6));
+    assert_eq!(bounds!(5, 6).merge(empty.clone()), bounds!(5, 6));
+    assert_eq!(empty.intersection(&bounds!(0, 10)), None);
+    assert_eq!(empty.complement(), Split::One(bounds!(,)));
+    assert_eq!(bounds!(1, 2).difference(&empty), Split::One(bounds!(1, 2)));
+    assert_eq!(empty.difference(&bounds!(1, 2)), Split::Empty);
+
+    assert!((empty.clone() + bounds!(1, 2)).is_empty());
+    assert!((bounds!(1) + empty.clone()).is_empty());
+    assert!((empty.clone() - bounds!(1, 2)).is_empty());
+    assert!((empty.clone() * bounds!(0)).is_empty());
+    assert!((bounds!(,) * empty.clone()).is_empty());
+    assert!((empty.clone() / bounds!(1, 2)).unwrap().is_empty());
+    assert!((bounds!(1, 2) / empty.clone()).unwrap().is_empty());
+    assert!((-empty).is_empty());
+}
+
+#[test]
+fn test_validate() {
+    assert_eq!(bounds!(1, 2).validate(), Ok(bounds!(1, 2)));
+    assert_eq!(bounds!(2).validate(), Ok(bounds!(2)));
+    assert_eq!(bounds!(,~2).validate(), Ok(bounds!(,~2)));
+    assert_eq!(
+        bounds!(3, 2).validate(),
+        Err(InvalidBounds {
+            lower: Some(Bound::inclusive(3)),
+            upper: Some(Bound::inclusive(2)),
+        })
+    );
+    assert!(bounds!(1,~1).validate().is_err());
+    assert_eq!(bounds!("a", "c").validate(), Ok(bounds!("a", "c")));
+    assert!(try_bounds!("c", "a").is_err());
+
+    assert_eq!(
+        Bounds::try_range(Some(Bound::exclusive(1)), None),
+        Ok(bounds!(~1,))
+    );
+    assert_eq!(
+        Bounds::try_range(Some(Bound::exclusive(1)), Some(Bound::exclusive(1))),
+        Err(InvalidBounds {
+            lower: Some(Bound::exclusive(1)),
+            upper: Some(Bound::exclusive(1)),
+        })
+    );
+    assert_eq!(
+        Bounds::try_range(Some(Bound::inclusive(3)), Some(Bound::exclusive(2)))
+            .unwrap_err()
+            .to_string(),
+        "invalid range [3, 2): start must be less than end"
+    );
+}
+
+#[test]
+fn test_contains() {
+    assert!(bounds!(1, 3).contains(&1));
+    assert!(bounds!(1, 3).contains(&2));
+    assert!(bounds!(1, 3).contains(&3));
+    assert!(!bounds!(1, 3).contains(&4));
+    assert!(!bounds!(~1,~3).contains(&1));
+    assert!(!bounds!(~1,~3).contains(&3));
+    assert!(bounds!(~1,~3).contains(&2));
+    assert!(bounds!(2).contains(&2));
+    assert!(!bounds!(2).contains(&3));
+    assert!(bounds!(,~2).contains(&-100));
+    assert!(!bounds!(,~2).contains(&2));
+    assert!(bounds!(2,).contains(&100));
+    assert!(bounds!(,).contains(&0));
+    assert!(!bounds!(3, 2).contains(&2));
+    assert!(!bounds!(~1,~1).contains(&1));
+}
+
+#[test]
+fn test_contains_bounds() {
+    assert!(bounds!(1, 4).contains_bounds(&bounds!(2, 3)));
+    assert!(bounds!(1, 4).contains_bounds(&bounds!(1, 4)));
+    assert!(bounds!(1, 4).contains_bounds(&bounds!(~1,~4)));
+    assert!(!bounds!(~1, 4).contains_bounds(&bounds!(1, 4)));
+    assert!(!bounds!(1,~4).contains_bounds(&bounds!(1, 4)));
+    assert!(!bounds!(1, 4).contains_bounds(&bounds!(2, 5)));
+    assert!(bounds!(1, 4).contains_bounds(&bounds!(4)));
+    assert!(!bounds!(1,~4).contains_bounds(&bounds!(4)));
+    assert!(bounds!(4).contains_bounds(&bounds!(4)));
+    assert!(bounds!(4).contains_bounds(&bounds!(4, 4)));
+    assert!(!bounds!(4).contains_bounds(&bounds!(4, 5)));
+    assert!(bounds!(,).contains_bounds(&bounds!(,~2)));
+    assert!(bounds!(,2).contains_bounds(&bounds!(,~2)));
+    assert!(!bounds!(,~2).contains_bounds(&bounds!(,2)));
+    assert!(!bounds!(1,).contains_bounds(&bounds!(,)));
+    assert!(bounds!(1, 2).contains_bounds(&bounds!(3, 2)));
+    assert!(!bounds!(3, 2).contains_bounds(&bounds!(1, 2)));
+
+    assert!(bounds!(1, 4).is_superset_of(&bounds!(2, 3)));
+    assert!(!bounds!(2, 3).is_superset_of(&bounds!(1, 4)));
+}
+
+#[test]
+fn test_div_extended() {
+    assert_eq!(
+        bounds!(1, 2).div_extended(bounds!(-1, 1)),
+        Split::Two(bounds!(,-1), bounds!(1,))
+    );
+    assert_eq!(
+        bounds!(-2, -1).div_extended(bounds!(-1, 1)),
+        Split::Two(bounds!(,-1), bounds!(1,))
+    );
+    assert_eq!(
+        bounds!(2, 4).div_extended(bounds!(-2, 1)),
+        Split::Two(bounds!(,-1), bounds!(2,))
+    );
+    assert_eq!(
+        bounds!(~2, 4).div_extended(bounds!(-2, ~1)),
+        Split::Two(bounds!(,~-1), bounds!(~2,))
+    );
+    assert_eq!(
+        bounds!(1, 2).div_extended(bounds!(,)),
+        Split::Two(bounds!(,~0), bounds!(~0,))
+    );
+    assert_eq!(
+        bounds!(2).div_extended(bounds!(-1, 1)),
+        Split::Two(bounds!(,-2), bounds!(2,))
+    );
+    assert_eq!(
+        bounds!(2, 4).div_extended(bounds!(0, 2)),
+        Split::One(bounds!(1,))
+    );
+    assert_eq!(
+        bounds!(2, 4).div_extended(bounds!(-2, 0)),
+        Split::One(bounds!(,-1))
+    );
+    assert_eq!(
+        bounds!(-4, -2).div_extended(bounds!(0, 2)),
+        Split::One(bounds!(,-1))
+    );
+    assert_eq!(
+        bounds!(4, 8).div_extended(bounds!(2)),
+        Split::One(bounds!(2, 4))
+    );
+    assert_eq!(
+        bounds!(4, 8).div_extended(bounds!(2, 4)),
+        Split::One(bounds!(1, 4))
+    );
+    assert_eq!(
+        bounds!(-1, 1).div_extended(bounds!(-1, 1)),
+        Split::One(bounds!(,))
+    );
+    assert_eq!(
+        bounds!(0).div_extended(bounds!(0, 1)),
+        Split::One(bounds!(,))
+    );
+    assert_eq!(bounds!(1, 2).div_extended(bounds!(0)), Split::Empty);
+    assert_eq!(bounds!(1, 2).div_extended(bounds!(0, 0)), Split::Empty);
+    assert_eq!(bounds!(1, 2).div_extended(bounds!(2, 1)), Split::Empty);
+}
+
+#[test]
+fn test_recip() {
+    assert_eq!(
+        bounds!(-1, 1).recip(),
+        Split::Two(bounds!(,-1), bounds!(1,))
+    );
+    assert_eq!(bounds!(0, 1).recip(), Split::One(bounds!(1,)));
+    assert_eq!(bounds!(~0, 1).recip(), Split::One(bounds!(1,)));
+    assert_eq!(bounds!(1,).recip(), Split::One(bounds!(~0, 1)));
+    assert_eq!(bounds!(-1).recip(), Split::One(bounds!(-1)));
+    assert_eq!(bounds!(0).recip(), Split::Empty);
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(bounds!(-2, 3) * bounds!(-2, 3), bounds!(-6, 9));
+    assert_eq!(bounds!(-2, 3).square(), bounds!(0, 9));
+    assert_eq!(bounds!(-3, 2).square(), bounds!(0, 9));
+    assert_eq!(bounds!(~-3, 2).square(), bounds!(0,~9));
+    assert_eq!(bounds!(~-3, 3).square(), bounds!(0, 9));
+    assert_eq!(bounds!(~-3,~3).square(), bounds!(0,~9));
+    assert_eq!(bounds!(2, 3).square(), bounds!(4, 9));
+    assert_eq!(bounds!(-3,~-2).square(), bounds!(~4, 9));
+    assert_eq!(bounds!(~0, 2).square(), bounds!(~0, 4));
+    assert_eq!(bounds!(-2,~0).square(), bounds!(~0, 4));
+    assert_eq!(bounds!(-2, 0).square(), bounds!(0, 4));
+    assert_eq!(bounds!(-2,).square(), bounds!(0,));
+    assert_eq!(bounds!(, 2).square(), bounds!(0,));
+    assert_eq!(bounds!(,-2).square(), bounds!(4,));
+    assert_eq!(bounds!(2,).square(), bounds!(4,));
+    assert_eq!(Bounds::<i32>::from(..).square(), bounds!(0,));
+    assert_eq!(bounds!(-3).square(), bounds!(9));
+
+    assert_eq!(bounds!(-2, 3).pow(3), bounds!(-8, 27));
+    assert_eq!(bounds!(~-2, 3).pow(3), bounds!(~-8, 27));
+    assert_eq!(bounds!(,-2).pow(3), bounds!(,-8));
+    assert_eq!(bounds!(-2, 3).pow(4), bounds!(0, 81));
+    assert_eq!(bounds!(-2, 3).pow(1), bounds!(-2, 3));
+    assert_eq!(bounds!(-2, 3).pow(0), bounds!(1));
+    assert!(bounds!(3, 2).pow(2).is_empty());
+}
+
+#[test]
+fn test_abs() {
+    assert_eq!(bounds!(-3).abs(), bounds!(3));
+    assert_eq!(bounds!(3).abs(), bounds!(3));
+    assert_eq!(bounds!(1,~3).abs(), bounds!(1,~3));
+    assert_eq!(bounds!(-3,~-1).abs(), bounds!(~1, 3));
+    assert_eq!(bounds!(-3, 2).abs(), bounds!(0, 3));
+    assert_eq!(bounds!(~-3, 2).abs(), bounds!(0,~3));
+    assert_eq!(bounds!(~-3,~3).abs(), bounds!(0,~3));
+    assert_eq!(bounds!(-3,~3).abs(), bounds!(0, 3));
+    assert_eq!(bounds!(-3,~0).abs(), bounds!(~0, 3));
+    assert_eq!(bounds!(,-2).abs(), bounds!(2,));
+    assert_eq!(bounds!(, 2).abs(), bounds!(0,));
+    assert_eq!(bounds!(-2,).abs(), bounds!(0,));
+    assert_eq!(Bounds::<i32>::from(..).abs(), bounds!(0,));
+    assert!(bounds!(3, 2).abs().is_empty());
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!(bounds!(1).min(bounds!(2)), bounds!(1));
+    assert_eq!(bounds!(1).max(bounds!(2)), bounds!(2));
+    assert_eq!(bounds!(1, 4).min(bounds!(2, 3)), bounds!(1, 3));
+    assert_eq!(bounds!(1, 4).max(bounds!(2, 3)), bounds!(2, 4));
+    assert_eq!(bounds!(1,~3).min(bounds!(2, 3)), bounds!(1,~3));
+    assert_eq!(bounds!(1,~3).max(bounds!(2, 3)), bounds!(2, 3));
+    assert_eq!(bounds!(~1, 3).min(bounds!(1, 4)), bounds!(1, 3));
+    assert_eq!(bounds!(~1, 3).max(bounds!(1, 4)), bounds!(~1, 4));
+    assert_eq!(bounds!(1, 5).min(bounds!(0)), bounds!(0));
+    assert_eq!(bounds!(1, 5).max(bounds!(0)), bounds!(1, 5));
+    assert_eq!(bounds!(1, 5).min(bounds!(3)), bounds!(1, 3));
+    assert_eq!(bounds!(1, 5).max(bounds!(3)), bounds!(3, 5));
+    assert_eq!(bounds!(1,).min(bounds!(, 3)), bounds!(, 3));
+    assert_eq!(bounds!(1,).max(bounds!(, 3)), bounds!(1,));
+    assert_eq!(bounds!(1,).min(bounds!(2,)), bounds!(1,));
+    assert_eq!(bounds!(, 1).max(bounds!(, 2)), bounds!(, 2));
+    assert_eq!(bounds!(, 1).min(bounds!(,)), bounds!(, 1));
+    assert!(bounds!(3, 2).min(bounds!(1)).is_empty());
+    assert!(bounds!(1).max(bounds!(3, 2)).is_empty());
+}
+
+#[test]
+#[allow(clippy::erasing_op)]
+fn test_scalar_ops() {
+    assert_eq!(bounds!(1,~3) + 5, bounds!(6,~8));
+    assert_eq!(bounds!(1) + 5, bounds!(6));
+    assert_eq!(bounds!(,~3) + 5, bounds!(,~8));
+    assert_eq!(5 + bounds!(~1, 3), bounds!(~6, 8));
+
+    assert_eq!(bounds!(1,~3) - 5, bounds!(-4,~-2));
+    assert_eq!(bounds!(~1,) - 5, bounds!(~-4,));
+    assert_eq!(5 - bounds!(1,~3), bounds!(~2, 4));
+    assert_eq!(5 - bounds!(,~3), bounds!(~2,));
+    assert_eq!(
+        5u32 - Bounds::Range(Some(Bound::inclusive(1u32)), None),
+        bounds!(,4)
+    );
+
+    assert_eq!(bounds!(1,~3) * 2, bounds!(2,~6));
+    assert_eq!(bounds!(1,~3) * -2, bounds!(~-6,-2));
+    assert_eq!(bounds!(1,) * -2, bounds!(,-2));
+    assert_eq!(bounds!(1,~3) * 0, bounds!(0));
+    assert_eq!(bounds!(,) * 0, bounds!(0));
+    assert_eq!(-2 * bounds!(1,~3), bounds!(~-6,-2));
+    assert_eq!(bounds!(3) * -2, bounds!(-6));
+
+    assert_eq!(bounds!(2,~6) / 2, Some(bounds!(1,~3)));
+    assert_eq!(bounds!(2,~6) / -2, Some(bounds!(~-3,-1)));
+    assert_eq!(bounds!(,~6) / -2, Some(bounds!(~-3,)));
+    assert_eq!(bounds!(2,~6) / 0, None);
+    assert_eq!(bounds!(6) / 2, Some(bounds!(3)));
+    assert_eq!(6 / bounds!(2,~3), Some(bounds!(~2, 3)));
+    assert_eq!(6 / bounds!(-1, 1), None);
+
+    assert!((bounds!(3, 2) + 1).is_empty());
+    assert!((bounds!(3, 2) * 0).is_empty());
+    assert!((bounds!(3, 2) / 2).unwrap().is_empty());
+
+    let half = Rational64::new(1, 2);
+    assert_eq!(
+        Bounds::from(Rational64::from(1)..Rational64::from(2)) * half,
+        Bounds::from(half..Rational64::from(1))
+    );
+    assert_eq!(
+        half + Bounds::Exact(half),
+        Bounds::Exact(Rational64::from(1))
+    );
+}
+
+#[test]
+fn test_ref_ops() {
+    let cases = [
+        (
+            bounds!(1,~3),
+            bounds!(~-2, 3),
+            bounds!(~-1,~6),
+            bounds!(-2,~5),
+            bounds!(~-6,~9),
+            None,
+        ),
+        (
+            bounds!(2,),
+            bounds!(,~-1),
+            bounds!(,),
+            bounds!(~3,),
+            bounds!(,~-2),
+            Some(bounds!(,~0)),
+        ),
+        (
+            bounds!(-3),
+            bounds!(2),
+            bounds!(-1),
+            bounds!(-5),
+            bounds!(-6),
+            Some(bounds!(-2, -1)),
+        ),
+        (
+            bounds!(~0, 2),
+            bounds!(-4),
+            bounds!(~-4, -2),
+            bounds!(~4, 6),
+            bounds!(-8,~0),
+            Some(bounds!(-1, 0)),
+        ),
+    ];
+    for (a, b, sum, difference, product, quotient) in cases {
+        assert_eq!(&a + &b, sum);
+        assert_eq!(a.clone() + &b, sum);
+        assert_eq!(&a + b.clone(), sum);
+        assert_eq!(a.clone() + b.clone(), sum);
+
+        assert_eq!(&a - &b, difference);
+        assert_eq!(a.clone() - &b, difference);
+        assert_eq!(&a - b.clone(), difference);
+        assert_eq!(a.clone() - b.clone(), difference);
+
+        assert_eq!(&a * &b, product);
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(&a * b.clone(), product);
+        assert_eq!(a.clone() * b.clone(), product);
+
+        assert_eq!(&a / &b, quotient);
+        assert_eq!(a.clone() / &b, quotient);
+        assert_eq!(&a / b.clone(), quotient);
+        assert_eq!(a.clone() / b.clone(), quotient);
+    }
+
+    let empty = bounds!(3, 2);
+    assert!((&empty + &bounds!(1)).is_empty());
+    assert!((bounds!(1) - empty.clone()).is_empty());
+    assert!((&bounds!(1) * &empty).is_empty());
+    assert!((empty / bounds!(1)).unwrap().is_empty());
+}
+
+/// A value that counts how often it is cloned, to check that operators don't clone endpoints.
+#[derive(PartialEq, PartialOrd, Debug)]
+struct Counted(i64);
+
+thread_local! {
+    static CLONES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Counted {
+        CLONES.with(|clones| clones.set(clones.get() + 1));
+        Counted(self.0)
+    }
+}
+
+impl Add for Counted {
+    type Output = Counted;
+
+    fn add(self, other: Counted) -> Counted {
+        Counted(self.0 + other.0)
+    }
+}
+
+impl Sub for Counted {
+    type Output = Counted;
+
+    fn sub(self, other: Counted) -> Counted {
+        Counted(self.0 - other.0)
+    }
+}
+
+impl ExactOps for Counted {}
+
+#[test]
+fn test_ref_ops_clones() {
+    let clones = || CLONES.with(|clones| clones.get());
+    let a = bounds!(Counted(1), ~Counted(3));
+    let b = bounds!(~Counted(-2),);
+
+    // the sum and difference of each pair of endpoints is computed from borrowed values, which
+    // `ExactOps` clones, but the endpoints themselves are never cloned
+    let before = clones();
+    assert_eq!(&a + &b, bounds!(~Counted(-1),));
+    assert_eq!(clones() - before, 2);
+    let before = clones();
+    assert_eq!(&a - &b, bounds!(,~Counted(5)));
+    assert_eq!(clones() - before, 2);
+    let before = clones();
+    assert_eq!(a + b, bounds!(~Counted(-1),));
+    assert_eq!(clones() - before, 2);
+}
+
+#[test]
+fn test_assign_ops() {
+    let mut x = bounds!(1,~3);
+    x += bounds!(1);
+    assert_eq!(x, bounds!(2,~4));
+    x += &bounds!(0, 1);
+    assert_eq!(x, bounds!(2,~5));
+    x += 1;
+    assert_eq!(x, bounds!(3,~6));
+
+    x -= bounds!(1);
+    assert_eq!(x, bounds!(2,~5));
+    x -= &bounds!(0, 1);
+    assert_eq!(x, bounds!(1,~5));
+    x -= 1;
+    assert_eq!(x, bounds!(0,~4));
+
+    x *= bounds!(2);
+    assert_eq!(x, bounds!(0,~8));
+    x *= &bounds!(-1);
+    assert_eq!(x, bounds!(~-8, 0));
+    x *= -1;
+    assert_eq!(x, bounds!(0,~8));
+
+    x /= bounds!(2);
+    assert_eq!(x, bounds!(0,~4));
+    x /= &bounds!(-2);
+    assert_eq!(x, bounds!(~-2, 0));
+    x /= -2;
+    assert_eq!(x, bounds!(0,~1));
+
+    x /= bounds!(-1, 1);
+    assert_eq!(x, bounds!(,));
+    let mut x = bounds!(1, 2);
+    x /= &bounds!(0);
+    assert_eq!(x, bounds!(,));
+    let mut x = bounds!(1, 2);
+    x /= 0;
+    assert_eq!(x, bounds!(,));
+}
+
+#[test]
+fn test_sum_product() {
+    let readings = vec![bounds!(1,~2), bounds!(~0, 1), bounds!(3)];
+    assert_eq!(readings.iter().sum::<Bounds<i32>>(), bounds!(~4,~6));
+    assert_eq!(
+        readings.clone().into_iter().sum::<Bounds<i32>>(),
+        bounds!(~4,~6)
+    );
+    assert_eq!(readings.iter().product::<Bounds<i32>>(), bounds!(~0,~6));
+    assert_eq!(
+        readings.into_iter().product::<Bounds<i32>>(),
+        bounds!(~0,~6)
+    );
+
+    assert_eq!(
+        Vec::<Bounds<i32>>::new().into_iter().sum::<Bounds<i32>>(),
+        bounds!(0)
+    );
+    assert_eq!(
+        Vec::<Bounds<i32>>::new()
+            .into_iter()
+            .product::<Bounds<i32>>(),
+        bounds!(1)
+    );
+}
+
+#[test]
+fn test_integer_div() {
+    // exclusive endpoints are replaced by the nearest integer inside the range
+    assert_eq!(bounds!(~1,~3).div_floor(&bounds!(2)), Some(bounds!(1)));
+    assert_eq!(bounds!(1, 3).div_floor(&bounds!(2)), Some(bounds!(0, 1)));
+    assert_eq!(bounds!(-3, 3).div_floor(&bounds!(2)), Some(bounds!(-2, 1)));
+    assert_eq!(bounds!(-3, 3).div_trunc(&bounds!(2)), Some(bounds!(-1, 1)));
+    assert_eq!(
+        bounds!(-7, 7).div_euclid(&bounds!(-2)),
+        Some(bounds!(-3, 4))
+    );
+    assert_eq!(
+        bounds!(7).div_euclid(&bounds!(-2, -1)),
+        Some(bounds!(-7, -3))
+    );
+    assert_eq!(
+        bounds!(-7).div_euclid(&bounds!(2, 3)),
+        Some(bounds!(-4, -3))
+    );
+
+    // divisors that may be zero
+    assert_eq!(bounds!(1, 3).div_floor(&bounds!(-1, 1)), None);
+    assert_eq!(
+        bounds!(1, 3).div_floor(&bounds!(~0, 1)),
+        Some(bounds!(1, 3))
+    );
+    assert_eq!(bounds!(1, 3).div_trunc(&bounds!(~-1, ~1)), None);
+
+    // unbounded sides
+    assert_eq!(bounds!(1, 3).div_floor(&bounds!(2,)), Some(bounds!(0, 1)));
+    assert_eq!(bounds!(-3, 3).div_floor(&bounds!(2,)), Some(bounds!(-2, 1)));
+    assert_eq!(
+        bounds!(-3, -1).div_euclid(&bounds!(,-2)),
+        Some(bounds!(1, 2))
+    );
+    assert_eq!(bounds!(1,).div_trunc(&bounds!(-2, -1)), Some(bounds!(,-0)));
+    assert_eq!(bounds!(1,).div_floor(&bounds!(1,)), Some(bounds!(0,)));
+
+    // no integer of the type lies beyond an exclusive endpoint at its limit
+    assert!(bounds!(~i32::MAX,)
+        .div_floor(&bounds!(2))
+        .unwrap()
+        .is_empty());
+    assert!(bounds!(1, 3)
+        .div_floor(&bounds!(, ~i32::MIN))
+        .unwrap()
+        .is_empty());
+    assert!(bounds!(, ~i32::MIN)
+        .div_trunc(&bounds!(2))
+        .unwrap()
+        .is_empty());
+    assert!(bounds!(~u8::MAX,)
+        .div_euclid(&bounds!(3))
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        bounds!(~i32::MAX - 1,).div_floor(&bounds!(1)),
+        Some(bounds!(i32::MAX,))
+    );
+
+    // every pair of integers in the operands lands in the result
+    let samples = [
+        bounds!(-5, 5),
+        bounds!(~-5, 4),
+        bounds!(2, ~9),
+        bounds!(-9, -2),
+        bounds!(3),
+    ];
+    for x in &samples {
+        for y in samples.iter().filter(|y| !y.contains(&0)) {
+            let floor = x.div_floor(y).unwrap();
+            let trunc = x.div_trunc(y).unwrap();
+            let euclid = x.div_euclid(y).unwrap();
+            let rem = x.rem_euclid(y).unwrap();
+            for a in (-10i32..10).filter(|a| x.contains(a)) {
+                for b in (-10..10).filter(|b| y.contains(b)) {
+                    assert!(floor.contains(&num::Integer::div_floor(&a, &b)));
+                    assert!(trunc.contains(&(a / b)));
+                    assert!(euclid.contains(&a.div_euclid(b)));
+                    assert!(rem.contains(&a.rem_euclid(b)));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rem_euclid() {
+    assert_eq!(bounds!(5, 7).rem_euclid(&bounds!(4)), Some(bounds!(1, 3)));
+    assert_eq!(
+        bounds!(-3, -1).rem_euclid(&bounds!(-4)),
+        Some(bounds!(1, 3))
+    );
+    assert_eq!(bounds!(~2, ~6).rem_euclid(&bounds!(4)), Some(bounds!(0, 3)));
+    assert_eq!(bounds!(~3, ~5).rem_euclid(&bounds!(4)), Some(bounds!(0)));
+    assert_eq!(
+        bounds!(1, 2).rem_euclid(&bounds!(3, 5)),
+        Some(bounds!(1, 2))
+    );
+    assert_eq!(
+        bounds!(1, 4).rem_euclid(&bounds!(3, 5)),
+        Some(bounds!(0, 4))
+    );
+    assert_eq!(bounds!(1, 4).rem_euclid(&bounds!(3,)), Some(bounds!(0, 4)));
+    assert_eq!(bounds!(0, 9).rem_euclid(&bounds!(,-2)), Some(bounds!(0, 9)));
+    assert_eq!(bounds!(5, 7).rem_euclid(&bounds!(2,)), Some(bounds!(0, 7)));
+    assert_eq!(bounds!(-1, 4).rem_euclid(&bounds!(3,)), Some(bounds!(0,)));
+    assert_eq!(bounds!(1,).rem_euclid(&bounds!(3,)), Some(bounds!(0,)));
+    assert_eq!(bounds!(13).rem_euclid(&bounds!(-5)), Some(bounds!(3)));
+    assert_eq!(bounds!(1, 4).rem_euclid(&bounds!(0, 5)), None);
+    assert!(bounds!(~i32::MAX,)
+        .rem_euclid(&bounds!(3))
+        .unwrap()
+        .is_empty());
+    assert!(bounds!(1, 4)
+        .rem_euclid(&bounds!(, ~i32::MIN))
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        bounds!(1u32, 9).rem_euclid(&bounds!(4)),
+        Some(bounds!(0, 3))
+    );
+}
+
+#[test]
+fn test_checked_ops() {
+    let a: Bounds<u8> = bounds!(100, ~200);
+    assert_eq!(a.checked_add(&bounds!(50)), Some(bounds!(150, ~250)));
+    assert_eq!(a.checked_add(&bounds!(60)), None);
+    assert_eq!(a.widening_add(&bounds!(60)), bounds!(160,));
+    assert_eq!(bounds!(200u8).checked_add(&bounds!(100)), None);
+    assert_eq!(bounds!(200u8).widening_add(&bounds!(100)), bounds!(,));
+    assert_eq!(
+        bounds!(-100i8, 100).widening_add(&bounds!(-100, 100)),
+        bounds!(,)
+    );
+    assert_eq!(
+        bounds!(1i8,).checked_add(&bounds!(~2, 3)),
+        Some(bounds!(~3,))
+    );
+
+    assert_eq!(a.checked_mul(&bounds!(1, 2)), None);
+    assert_eq!(a.widening_mul(&bounds!(1, 2)), bounds!(100,));
+    assert_eq!(
+        bounds!(10u8, 20).checked_mul(&bounds!(~2, 3)),
+        Some(bounds!(~20, 60))
+    );
+    assert_eq!(bounds!(16u8).checked_mul(&bounds!(16)), None);
+    assert_eq!(
+        bounds!(0u8).checked_mul(&bounds!(200, 255)),
+        Some(bounds!(0))
+    );
+
+    // the sign of the overflowing product picks the side that becomes unbounded
+    let b: Bounds<i8> = bounds!(-100, 2);
+    assert_eq!(b.widening_mul(&bounds!(1, 3)), bounds!(, 6));
+    assert_eq!(b.widening_mul(&bounds!(-3, -1)), bounds!(-6,));
+    assert_eq!(b.checked_mul(&bounds!(-1, 1)), Some(bounds!(-100, 100)));
+    assert_eq!(b.checked_mul(&bounds!(2,)), None);
+    assert_eq!(
+        bounds!(-10i8, 2).checked_mul(&bounds!(2,)),
+        Some(bounds!(,))
+    );
+}
+
+#[test]
+fn test_unsigned_sub() {
+    assert_eq!(bounds!(5u32, ~9) - bounds!(~1, 2), bounds!(3, ~8));
+    assert_eq!(&bounds!(5u32, ~9) - &bounds!(~1, 2), bounds!(3, ~8));
+    assert_eq!(bounds!(5u32) - bounds!(1, ~3), bounds!(~2, 4));
+    assert_eq!(bounds!(5u32, 9) - bounds!(1,), bounds!(, 8));
+    assert_eq!(
+        bounds!(BigUint::from(5u32)) - bounds!(BigUint::from(2u32)),
+        bounds!(BigUint::from(3u32))
+    );
+    let mut x = bounds!(5usize, 9);
+    x -= bounds!(1, 2);
+    assert_eq!(x, bounds!(3, 8));
+
+    assert_eq!(
+        bounds!(5u32, 9).checked_sub(&bounds!(1, 5)),
+        Some(bounds!(0, 8))
+    );
+    assert_eq!(bounds!(5u32, 9).checked_sub(&bounds!(1, 6)), None);
+    assert_eq!(bounds!(5u32, 9).widening_sub(&bounds!(1, 6)), bounds!(, 8));
+    assert_eq!(
+        bounds!(~5u32, 9).checked_sub(&bounds!(1, ~5)),
+        Some(bounds!(~0, 8))
+    );
+    assert_eq!(bounds!(2u32).checked_sub(&bounds!(3)), None);
+}
+
+#[test]
+fn test_zero_factor_exclusivity() {
+    assert_eq!(bounds!(0, 1) * bounds!(~2, ~3), bounds!(0, ~3));
+    assert_eq!(&bounds!(~2, ~3) * &bounds!(0, 1), bounds!(0, ~3));
+    assert_eq!(bounds!(~0, 1) * bounds!(~2, ~3), bounds!(~0, ~3));
+    assert_eq!(bounds!(-1, 0) * bounds!(~2, ~3), bounds!(~-3, 0));
+    assert_eq!(bounds!(0,) * bounds!(~2, ~3), bounds!(0,));
+
+    let r = Rational64::from_integer;
+    let half = Rational64::new(1, 2);
+    assert_eq!(
+        bounds!(r(0), r(1)) / bounds!(~r(2), ~r(3)),
+        Some(bounds!(r(0), ~half))
+    );
+    assert_eq!(
+        bounds!(~r(0), r(1)) / bounds!(r(1), r(2)),
+        Some(bounds!(~r(0), r(1)))
+    );
+    assert_eq!(
+        bounds!(~r(0), r(1)) / bounds!(r(1),),
+        Some(bounds!(~r(0), r(1)))
+    );
+    assert_eq!(
+        bounds!(r(0), r(1)) / bounds!(r(1),),
+        Some(bounds!(r(0), r(1)))
+    );
+    assert_eq!(
+        bounds!(~r(0), r(1)) / bounds!(~r(0), r(2)),
+        Some(bounds!(~r(0),))
+    );
+    assert_eq!(
+        bounds!(r(0), r(1)) / bounds!(~r(0), r(2)),
+        Some(bounds!(r(0),))
+    );
+    assert_eq!(
+        bounds!(r(-1), r(1)) / bounds!(~r(-2), ~r(0)),
+        Some(bounds!(,))
+    );
+}
+
+#[test]
+fn test_checked_div() {
+    assert_eq!(bounds!(2, 6).checked_div(&bounds!(2)), Ok(bounds!(1, 3)));
+    assert_eq!(bounds!(1, 3).checked_div(&bounds!(2)), Ok(bounds!(0, 2)));
+    assert_eq!(bounds!(7).checked_div(&bounds!(-2)), Ok(bounds!(-4, -3)));
+    assert_eq!(bounds!(5, 7).checked_div(&bounds!(2, 3)), Ok(bounds!(1, 4)));
+    assert_eq!(
+        bounds!(2, 6).checked_div(&bounds!(0)),
+        Err(DivError::DivisorIsZero)
+    );
+    assert_eq!(
+        bounds!(2, 6).checked_div(&bounds!(0, 0)),
+        Err(DivError::DivisorIsZero)
+    );
+    assert_eq!(
+        bounds!(2, 6).checked_div(&bounds!(-1, 1)),
+        Err(DivError::DivisorContainsZero {
+            divisor: bounds!(-1, 1)
+        })
+    );
+    assert_eq!(
+        bounds!(0).checked_div(&bounds!(0, 1)),
+        Err(DivError::DivisorContainsZero {
+            divisor: bounds!(0, 1)
+        })
+    );
+    assert_eq!(bounds!(0).checked_div(&bounds!(~0, 1)), Ok(bounds!(0)));
+    assert_eq!(bounds!(2, 6).checked_div(&bounds!(~0, 1)), Ok(bounds!(2,)));
+    assert_eq!(
+        bounds!(i32::MIN, 0).checked_div(&bounds!(-1)),
+        Err(DivError::Overflow)
+    );
+    assert_eq!(
+        bounds!(i32::MIN).checked_div(&bounds!(-2, -1)),
+        Err(DivError::Overflow)
+    );
+    assert_eq!(
+        bounds!(i32::MIN + 1, 0).checked_div(&bounds!(-1)),
+        Ok(bounds!(0, i32::MAX))
+    );
+    assert_eq!(
+        DivError::DivisorContainsZero {
+            divisor: bounds!(-1, 1)
+        }
+        .to_string(),
+        "division by [-1, 1], which contains zero"
+    );
+}
+
+#[test]
+fn test_endpoint() {
+    let finite = |x: i32| Endpoint::Finite(Bound::inclusive(x));
+    let b = bounds!(~1, 3);
+    assert_eq!(b.lower_endpoint(), Endpoint::Finite(Bound::exclusive(&1)));
+    assert_eq!(b.upper_endpoint(), Endpoint::Finite(Bound::inclusive(&3)));
+    assert_eq!(bounds!(, 3).lower_endpoint(), Endpoint::NegInfinity);
+    assert_eq!(bounds!(1,).upper_endpoint(), Endpoint::<&i32>::PosInfinity);
+    assert_eq!(Endpoint::<i32>::lower(None), Endpoint::NegInfinity);
+    assert_eq!(Endpoint::upper(Some(Bound::inclusive(2))), finite(2));
+    assert_eq!(finite(2).into_bound(), Some(Bound::inclusive(2)));
+    assert_eq!(Endpoint::<i32>::PosInfinity.into_bound(), None);
+    assert_eq!(-Endpoint::<i32>::NegInfinity, Endpoint::PosInfinity);
+
+    assert_eq!(finite(2) + finite(3), Some(finite(5)));
+    assert_eq!(
+        finite(2) + Endpoint::PosInfinity,
+        Some(Endpoint::PosInfinity)
+    );
+    assert_eq!(Endpoint::<i32>::NegInfinity + Endpoint::PosInfinity, None);
+    assert_eq!(
+        finite(2) - Endpoint::PosInfinity,
+        Some(Endpoint::NegInfinity)
+    );
+    assert_eq!(Endpoint::<i32>::PosInfinity - Endpoint::PosInfinity, None);
+    assert_eq!(
+        finite(-2) * Endpoint::PosInfinity,
+        Some(Endpoint::NegInfinity)
+    );
+    assert_eq!(
+        Endpoint::<i32>::NegInfinity * Endpoint::NegInfinity,
+        Some(Endpoint::PosInfinity)
+    );
+    assert_eq!(finite(0) * Endpoint::PosInfinity, Some(finite(0)));
+    assert_eq!(
+        Endpoint::Finite(Bound::exclusive(0)) * Endpoint::PosInfinity,
+        None
+    );
+
+    assert_eq!(
+        finite(2).lower_min(Endpoint::NegInfinity),
+        Endpoint::NegInfinity
+    );
+    assert_eq!(finite(2).lower_max(Endpoint::NegInfinity), finite(2));
+    assert_eq!(finite(2).upper_min(Endpoint::PosInfinity), finite(2));
+    assert_eq!(
+        finite(2).upper_max(Endpoint::PosInfinity),
+        Endpoint::PosInfinity
+    );
+    assert_eq!(
+        finite(2).upper_max(Endpoint::Finite(Bound::exclusive(2))),
+        finite(2)
+    );
+}
+
+#[test]
+fn test_mul_div_samples() {
+    let r = |n: i64, d: i64| Rational64::new(n, d);
+    let samples = [
+        bounds!(r(-2, 1), r(3, 1)),
+        bounds!(~r(0, 1), r(2, 1)),
+        bounds!(r(0, 1), ~r(1, 2)),
+        bounds!(~r(-3, 1), ~r(0, 1)),
+        bounds!(, r(-1, 1)),
+        bounds!(~r(1, 1),),
+        bounds!(r(0, 1),),
+        bounds!(,),
+        bounds!(r(3, 2)),
+        bounds!(r(0, 1)),
+    ];
+    let points: Vec<Rational64> = (-16..=16).map(|n| r(n, 4)).collect();
+    for x in &samples {
+        for y in &samples {
+            let product = x * y;
+            let quotient = x / y;
+            for a in points.iter().filter(|a| x.contains(a)) {
+                for b in points.iter().filter(|b| y.contains(b)) {
+                    assert!(product.contains(&(a * b)), "{:?} * {:?}", x, y);
+                    if let Some(quotient) = &quotient {
+                        assert!(quotient.contains(&(a / b)), "{:?} / {:?}", x, y);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_floats() {
+    let a = bounds!(1.5, ~4.0);
+    let b = bounds!(~-2.0, 0.5);
+    assert_eq!(&a + &b, bounds!(~-0.5, ~4.5));
+    assert_eq!(&a - &b, bounds!(1.0, ~6.0));
+    assert_eq!(&a * &b, bounds!(~-8.0, ~2.0));
+    assert_eq!(&a / &bounds!(2.0), Some(bounds!(0.75, ~2.0)));
+    assert_eq!(&a / &b, None);
+    assert_eq!(a.clone().merge(b.clone()), bounds!(~-2.0, ~4.0));
+    assert!(a.contains(&3.9));
+    assert!(!a.contains(&f64::NAN));
+    assert_eq!(a.intersection(&bounds!(3.0,)), Some(bounds!(3.0, ~4.0)));
+    assert_eq!(b.clone().abs(), bounds!(0.0, ~2.0));
+    assert_eq!(a.clone().max(b), a);
+    assert_eq!(bounds!(1.0f32, 2.0) * bounds!(3.0), bounds!(3.0, 6.0));
+    assert_eq!(
+        [bounds!(0.0, 1.0), bounds!(~1.0, 2.0)]
+            .into_iter()
+            .collect::<BoundsSet<f64>>(),
+        BoundsSet::from(bounds!(0.0, 2.0))
+    );
+}
+
+#[test]
+fn test_rounded_floats() {
+    let exact = |x: f64| BigRational::from_float(x).unwrap();
+    let tenth = BigRational::new(1.into(), 10.into());
+    let encloses = |bounds: &Bounds<f64>, value: &BigRational| {
+        exact(*bounds.lower().unwrap().value) <= *value
+            && exact(*bounds.upper().unwrap().value) >= *value
+    };
+
+    let sum = bounds!(0.1) + bounds!(0.2);
+    assert!(sum.lower().unwrap().value < sum.upper().unwrap().value);
+    assert!(encloses(&sum, &(exact(0.1) + exact(0.2))));
+    let product = bounds!(0.1, 0.2) * bounds!(0.1, 0.3);
+    assert!(encloses(&product, &(exact(0.1) * exact(0.1))));
+    assert!(encloses(&product, &(exact(0.2) * exact(0.3))));
+    let quotient = (bounds!(1.0) / bounds!(3.0)).unwrap();
+    assert!(encloses(&quotient, &BigRational::new(1.into(), 3.into())));
+    let three = BigRational::from_integer(3.into());
+    let scaled = bounds!(0.1, ~0.2) * -3.0;
+    assert_eq!(scaled.lower().unwrap().bound_type, BoundType::Exclusive);
+    assert!(encloses(&scaled, &(-exact(0.1) * &three)));
+    assert!(encloses(&scaled, &(-exact(0.2) * &three)));
+    let shifted = 1.0 - bounds!(0.1, 0.2);
+    assert!(encloses(&shifted, &(exact(1.0) - exact(0.1))));
+    assert!(encloses(&shifted, &(exact(1.0) - exact(0.2))));
+    let divided = (bounds!(1.0, 2.0) / 3.0).unwrap();
+    assert!(encloses(&divided, &(exact(1.0) / &three)));
+    assert!(encloses(&divided, &(exact(2.0) / &three)));
+    let power = bounds!(-0.1, 0.1).pow(3);
+    assert!(encloses(&power, &(-exact(0.1) * exact(0.1) * exact(0.1))));
+
+    // exact results stay exact
+    assert_eq!(bounds!(0.5) + bounds!(0.25), bounds!(0.75));
+    assert_eq!(bounds!(0.1) - bounds!(0.1), bounds!(0.0));
+    assert_eq!(bounds!(1.5, 2.0) * bounds!(2.0), bounds!(3.0, 4.0));
+    assert_eq!(bounds!(-3.0, 2.0).pow(2), bounds!(0.0, 9.0));
+
+    assert_eq!(f64::MAX.add_down(&f64::MAX), f64::MAX);
+    assert_eq!(f64::MAX.add_up(&f64::MAX), f64::INFINITY);
+    assert_eq!(1.0f32.div_down(&3.0).next_up(), 1.0f32.div_up(&3.0));
+    assert_eq!(tenth.mul_down(&tenth), tenth.mul_up(&tenth));
+}
+
+#[test]
+fn test_elementary() {
+    let precision = 1e-12;
+    assert_eq!(bounds!(-1.0, 4.0).sqrt(&precision), Some(bounds!(0.0, 2.0)));
+    assert_eq!(bounds!(-3.0, ~-1.0).sqrt(&precision), None);
+    assert_eq!(
+        bounds!(~0.25, ~9.0).sqrt(&precision),
+        Some(bounds!(~0.5, ~3.0))
+    );
+    assert_eq!(bounds!(, 0.0).exp(&precision), bounds!(~0.0, 1.0));
+    assert_eq!(bounds!(0.0, 1.0).ln(&precision), Some(bounds!(, 0.0)));
+    assert_eq!(bounds!(-2.0, 0.0).ln(&precision), None);
+    assert_eq!(
+        bounds!(~0.125, ~8.0).log2(&precision),
+        Some(bounds!(~-3.0, ~3.0))
+    );
+
+    let e = bounds!(1.0).exp(&0.0);
+    let (low, high) = (*e.lower().unwrap().value, *e.upper().unwrap().value);
+    assert!(low < high && low >= std::f64::consts::E.next_down());
+    assert!(high <= std::f64::consts::E.next_up());
+    let ln = bounds!(1.0, ~2.0).ln(&precision).unwrap();
+    assert_eq!(ln.lower(), Some(Bound::inclusive(&0.0)));
+    assert!(*ln.upper().unwrap().value >= std::f64::consts::LN_2);
+    assert_eq!(0.0.exp_enclosure(&precision), (1.0, 1.0));
+    assert_eq!(1000.0.exp_enclosure(&precision), (f64::MAX, f64::INFINITY));
+    // an overflowing result leaves the upper side unbounded instead of ending at infinity
+    assert_eq!(bounds!(1000.0).exp(&precision), bounds!(f64::MAX,));
+    let large = bounds!(1.0, 1000.0).exp(&precision);
+    assert_eq!(large.upper(), None);
+    assert!(*large.lower().unwrap().value <= std::f64::consts::E);
+
+    let rational = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+    let digits = |s: &str| {
+        let denom = num::BigInt::from(10).pow((s.len() - s.find('.').unwrap() - 1) as u32);
+        BigRational::new(s.replace('.', "").parse().unwrap(), denom)
+    };
+    let precision = rational(1, 1_000_000_000_000_000_000);
+    let enclosure = |bounds: &Bounds<BigRational>| {
+        let (low, high) = (bounds.lower().unwrap().value, bounds.upper().unwrap().value);
+        assert!(high - low <= precision);
+        (low.clone(), high.clone())
+    };
+
+    let (low, high) = enclosure(&bounds!(rational(1, 1)).exp(&precision));
+    assert!(low <= digits("2.718281828459045235361") && high >= digits("2.718281828459045235360"));
+    let (low, high) = enclosure(&bounds!(rational(-1, 1)).exp(&precision));
+    assert!(low <= digits("0.367879441171442321596") && high >= digits("0.367879441171442321595"));
+    let (low, high) = enclosure(&bounds!(rational(2, 1)).ln(&precision).unwrap());
+    assert!(low <= digits("0.693147180559945309418") && high >= digits("0.693147180559945309417"));
+    let (low, high) = enclosure(&bounds!(rational(1, 3)).log2(&precision).unwrap());
+    assert!(
+        low <= digits("-1.584962500721156181453") && high >= digits("-1.584962500721156181454")
+    );
+    let (low, high) = enclosure(&bounds!(rational(2, 1)).sqrt(&precision).unwrap());
+    assert!(&low * &low <= rational(2, 1) && &high * &high >= rational(2, 1));
+
+    let root = bounds!(~rational(-1, 1), ~rational(9, 4)).sqrt(&precision);
+    assert_eq!(root, Some(bounds!(rational(0, 1), ~rational(3, 2))));
+    let log = bounds!(rational(1, 4), rational(1024, 1)).log2(&precision);
+    assert_eq!(log, Some(bounds!(rational(-2, 1), rational(10, 1))));
+}
+
+#[test]
+fn test_powf() {
+    let value = |bound: Option<Bound<&f64>>| *bound.unwrap().value;
+    let power = bounds!(2.0, 4.0).powf(&bounds!(0.5, 2.0), &1e-12).unwrap();
+    assert!(value(power.lower()) <= 2f64.sqrt() && value(power.lower()) > 1.41);
+    assert!(value(power.upper()) >= 16.0 && value(power.upper()) < 16.01);
+    let power = bounds!(~0.0, 4.0).powf(&bounds!(0.5), &1e-12).unwrap();
+    assert_eq!(power.lower(), Some(Bound::exclusive(&0.0)));
+    let power = bounds!(0.0, 4.0).powf(&bounds!(0.5, 1.0), &1e-12).unwrap();
+    assert_eq!(power.lower(), Some(Bound::inclusive(&0.0)));
+    assert!(value(power.upper()) >= 4.0);
+    let power = bounds!(0.0, 1.0)
+        .powf(&bounds!(-1.0, -0.5), &1e-12)
+        .unwrap();
+    assert_eq!(power.lower(), Some(Bound::inclusive(&1.0)));
+    assert_eq!(power.upper(), None);
+    assert_eq!(
+        bounds!(0.0).powf(&bounds!(~0.0, 2.0), &1e-12),
+        Some(bounds!(0.0))
+    );
+    assert_eq!(bounds!(0.0).powf(&bounds!(-1.0, 0.0), &1e-12), None);
+    assert_eq!(bounds!(-4.0, -1.0).powf(&bounds!(0.5), &1e-12), None);
+
+    // integer exponents are exact, and defined for negative bases
+    let rational = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+    let precision = rational(1, 1000);
+    assert_eq!(
+        bounds!(rational(2, 3)).powf(&bounds!(rational(3, 1)), &precision),
+        Some(bounds!(rational(8, 27)))
+    );
+    assert_eq!(
+        bounds!(rational(-2, 1), rational(3, 1)).powf(&bounds!(rational(2, 1)), &precision),
+        Some(bounds!(rational(0, 1), rational(9, 1)))
+    );
+    assert_eq!(
+        bounds!(rational(2, 1), ~rational(4, 1)).powf(&bounds!(rational(-1, 1)), &precision),
+        Some(bounds!(~rational(1, 4), rational(1, 2)))
+    );
+    assert_eq!(
+        bounds!(rational(-1, 1), rational(1, 1)).powf(&bounds!(rational(-2, 1)), &precision),
+        None
+    );
+    assert_eq!(
+        bounds!(-3.0).powf(&bounds!(3.0), &1e-12),
+        Some(bounds!(-27.0))
+    );
+}
+
+#[test]
+fn test_round() {
+    assert_eq!(bounds!(1.2, ~3.7).floor(), bounds!(1.0, 3.0));
+    assert_eq!(bounds!(~1.0, ~4.0).floor(), bounds!(1.0, 3.0));
+    assert_eq!(bounds!(~1.0, ~4.0).ceil(), bounds!(2.0, 4.0));
+    assert_eq!(bounds!(1.2, 1.7).floor(), bounds!(1.0));
+    assert_eq!(bounds!(~1.0, ~2.0).ceil(), bounds!(2.0));
+    assert_eq!(bounds!(~-3.0, ~2.0).trunc(), bounds!(-2.0, 1.0));
+    assert_eq!(bounds!(~0.0, ~1.0).trunc(), bounds!(0.0));
+    assert_eq!(bounds!(~-1.0, ~0.0).trunc(), bounds!(0.0));
+    assert_eq!(bounds!(~-2.5, ~2.5).round(), bounds!(-2.0, 2.0));
+    assert_eq!(bounds!(-2.5, 2.5).round(), bounds!(-3.0, 3.0));
+    assert_eq!(bounds!(, ~0.5).round(), bounds!(, 0.0));
+    assert_eq!(bounds!(2.7).floor(), bounds!(2.0));
+    assert_eq!(bounds!(~1.0, 1.0).floor(), bounds!(~1.0, 1.0));
+
+    let rational = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+    assert_eq!(
+        bounds!(rational(6, 5), ~rational(37, 10)).floor(),
+        bounds!(rational(1, 1), rational(3, 1))
+    );
+    assert_eq!(
+        bounds!(~rational(-7, 2), ~rational(3, 1)).round(),
+        bounds!(rational(-3, 1), rational(3, 1))
+    );
+    assert_eq!(
+        bounds!(~rational(-3, 1), rational(1, 3)).ceil(),
+        bounds!(rational(-2, 1), rational(1, 1))
+    );
+}
+
+#[test]
+fn test_trig() {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    let value = |bound: Option<Bound<&f64>>| *bound.unwrap().value;
+
+    let sin = bounds!(0.0, 2.0).sin();
+    assert_eq!(sin.lower(), Some(Bound::inclusive(&0.0)));
+    assert_eq!(sin.upper(), Some(Bound::inclusive(&1.0)));
+    let sin = bounds!(~4.0, ~5.0).sin();
+    assert_eq!(sin.lower(), Some(Bound::inclusive(&-1.0)));
+    assert!(sin.upper().unwrap().bound_type == crate::BoundType::Exclusive);
+    assert!(value(sin.upper()) >= 4f64.sin() && value(sin.upper()) < -0.75);
+    let sin = bounds!(0.5, 1.0).sin();
+    assert!(value(sin.lower()) <= 0.5f64.sin() && value(sin.upper()) >= 1f64.sin());
+    assert!(value(sin.upper()) < 1.0);
+    assert_eq!(bounds!(-10.0, 10.0).sin(), bounds!(-1.0, 1.0));
+    assert_eq!(bounds!(1.0,).sin(), bounds!(-1.0, 1.0));
+    assert_eq!(bounds!(0.0).sin(), bounds!(0.0));
+
+    let cos = bounds!(-1.0, 4.0).cos();
+    assert_eq!(cos, bounds!(-1.0, 1.0));
+    let cos = bounds!(-1.0, 0.5).cos();
+    assert_eq!(cos.upper(), Some(Bound::inclusive(&1.0)));
+    assert!(value(cos.lower()) <= 1f64.cos() && value(cos.lower()) > 0.5);
+    let cos = bounds!(2.0, 3.0).cos();
+    assert!(value(cos.lower()) > -1.0 && value(cos.upper()) < 0.0);
+    assert_eq!(bounds!(0.0).cos(), bounds!(1.0));
+    assert_eq!(
+        bounds!(0.0, 0.5).cos().upper(),
+        Some(Bound::inclusive(&1.0))
+    );
+
+    match bounds!(0.0, 1.0).tan() {
+        Split::One(tan) => {
+            assert_eq!(tan.lower(), Some(Bound::inclusive(&0.0)));
+            assert!(value(tan.upper()) >= 1f64.tan());
+        }
+        split => panic!("{:?}", split),
+    }
+    match bounds!(1.0, ~2.0).tan() {
+        Split::Two(below, above) => {
+            assert_eq!(below.lower(), None);
+            assert!(value(below.upper()) >= 2f64.tan());
+            assert!(value(above.lower()) <= 1f64.tan());
+            assert_eq!(above.upper(), None);
+        }
+        split => panic!("{:?}", split),
+    }
+    assert_eq!(bounds!(1.0, 5.0).tan(), Split::One(bounds!(,)));
+    assert_eq!(bounds!(0.0, 4.0).tan(), Split::One(bounds!(,)));
+    assert!(matches!(
+        bounds!(-FRAC_PI_2, FRAC_PI_2).tan(),
+        Split::One(_)
+    ));
+
+    let angle = bounds!(1.0, 2.0).atan2(&bounds!(1.0, 2.0));
+    assert!(value(angle.lower()) <= 0.5f64.atan() && value(angle.upper()) >= 2f64.atan());
+    let angle = bounds!(-1.0, 1.0).atan2(&bounds!(-2.0, -1.0));
+    assert_eq!(angle, bounds!(-PI.next_up(), PI.next_up()));
+    let angle = bounds!(-1.0, 1.0).atan2(&bounds!(~0.0,));
+    assert!(value(angle.lower()) <= -FRAC_PI_2 && value(angle.upper()) >= FRAC_PI_2);
+    assert_eq!(
+        angle.upper().unwrap().bound_type,
+        crate::BoundType::Exclusive
+    );
+    let angle = bounds!(1.0,).atan2(&bounds!(0.0,));
+    assert_eq!(angle.lower(), Some(Bound::exclusive(&0.0)));
+    assert!(value(angle.upper()) >= FRAC_PI_2);
+    assert_eq!(bounds!(0.0).atan2(&bounds!(2.0)), bounds!(0.0));
+}
+
+#[test]
+fn test_validate_floats() {
+    assert_eq!(try_bounds!(0.0, f64::INFINITY), Ok(bounds!(0.0,)));
+    assert_eq!(try_bounds!(~f64::NEG_INFINITY, 1.0), Ok(bounds!(, 1.0)));
+    assert_eq!(
+        bounds!(f64::NEG_INFINITY, f64::INFINITY).validate(),
+        Ok(bounds!(,))
+    );
+    assert_eq!(try_bounds!(2.0), Ok(bounds!(2.0)));
+    assert!(try_bounds!(f64::INFINITY,).is_err());
+    assert!(try_bounds!(, f64::NEG_INFINITY).is_err());
+    assert!(try_bounds!(f64::INFINITY).is_err());
+    assert_eq!(
+        try_bounds!(f64::INFINITY, 5.0),
+        Err(InvalidBounds {
+            lower: Some(Bound::inclusive(f64::INFINITY)),
+            upper: Some(Bound::inclusive(5.0)),
+        })
+    );
+    assert_eq!(
+        try_bounds!(f64::NEG_INFINITY, f64::NEG_INFINITY),
+        Err(InvalidBounds {
+            lower: Some(Bound::inclusive(f64::NEG_INFINITY)),
+            upper: Some(Bound::inclusive(f64::NEG_INFINITY)),
+        })
+    );
+    assert!(try_bounds!(f64::NAN).is_err());
+    assert!(try_bounds!(1.0f32, f32::NAN).is_err());
+    assert!(try_bounds!(~1.0, 1.0).is_err());
+
+    let error = try_bounds!(f64::NAN, 1.0).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid range [NaN, 1.0]: endpoints must not be NaN"
+    );
+
+    // zero times an infinite endpoint is exactly zero, not NaN
+    assert_eq!(
+        bounds!(0.0, 1.0) * bounds!(1.0, f64::INFINITY),
+        bounds!(0.0, f64::INFINITY)
+    );
+    assert_eq!(
+        bounds!(~0.0, 1.0) * bounds!(1.0, f64::INFINITY),
+        bounds!(~0.0, f64::INFINITY)
+    );
+    assert_eq!(
+        Bounds::Exact(0.0) * Bounds::Exact(f64::INFINITY),
+        bounds!(0.0)
+    );
+    assert_eq!(
+        bounds!(0.0, 1.0) * f64::INFINITY,
+        bounds!(0.0, f64::INFINITY)
+    );
+    // an infinite quotient of infinite endpoints could be any value of its sign
+    let quotient = (bounds!(1.0, f64::INFINITY) / bounds!(1.0, f64::INFINITY)).unwrap();
+    assert_eq!(quotient.upper(), None);
+    assert!(*quotient.lower().unwrap().value <= 0.0);
+}
+
+/// An exact type from outside the crate, which only has some of the operators.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+struct Cents(u64);
+
+impl Add for Cents {
+    type Output = Cents;
+
+    fn add(self, other: Cents) -> Cents {
+        Cents(self.0 + other.0)
+    }
+}
+
+impl Sub for Cents {
+    type Output = Cents;
+
+    fn sub(self, other: Cents) -> Cents {
+        Cents(self.0 - other.0)
+    }
+}
+
+impl ExactOps for Cents {}
+
+#[test]
+fn test_exact_ops() {
+    assert_eq!(
+        bounds!(Cents(5), Cents(9)) + bounds!(Cents(2)),
+        bounds!(Cents(7), Cents(11))
+    );
+    assert_eq!(bounds!(Cents(5),) + Cents(1), bounds!(Cents(6),));
+    let total: Bounds<Cents> = vec![bounds!(Cents(1), Cents(2)), bounds!(Cents(3))]
+        .into_iter()
+        .fold(bounds!(Cents(0)), |acc, x| acc + x);
+    assert_eq!(total, bounds!(Cents(4), Cents(5)));
+    // subtraction only needs `Sub`, not the other operators
+    assert_eq!(
+        bounds!(Cents(5), Cents(9)) - bounds!(Cents(2), Cents(3)),
+        bounds!(Cents(2), Cents(7))
+    );
+    let mut remaining = bounds!(Cents(10), Cents(12));
+    remaining -= Cents(4);
+    assert_eq!(remaining, bounds!(Cents(6), Cents(8)));
 }
@@ -0,0 +1,240 @@
+use crate::bound::{Bound, BoundType};
+use crate::bounds::{enclose, hull, rounded_candidates, Bounds};
+use crate::endpoint::Endpoint;
+use crate::rounded::{RoundedDiv, RoundedMul, RoundedSub};
+use crate::sign_bounds::SignBounds;
+use crate::split::Split;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// `PI` is the float just below π, so this is the float just above it.
+const PI_UP: f64 = PI.next_up();
+
+/// Widens a result of the platform's math library, which isn't correctly rounded, but is within
+/// one step of the exact result. Two steps each way are enough to contain it.
+fn widen(value: f64) -> (f64, f64) {
+    (value.next_down().next_down(), value.next_up().next_up())
+}
+
+fn sin_enclosure(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (x, x);
+    }
+    let (low, high) = widen(x.sin());
+    (low.max(-1.0), high.min(1.0))
+}
+
+fn cos_enclosure(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (1.0, 1.0);
+    }
+    let (low, high) = widen(x.cos());
+    (low.max(-1.0), high.min(1.0))
+}
+
+fn tan_enclosure(x: f64) -> (f64, f64) {
+    if x == 0.0 {
+        return (x, x);
+    }
+    widen(x.tan())
+}
+
+fn atan2_enclosure(y: f64, x: f64) -> (f64, f64) {
+    if y == 0.0 && x > 0.0 {
+        return (0.0, 0.0);
+    }
+    let (low, high) = widen(y.atan2(x));
+    (low.max(-PI_UP), high.min(PI_UP))
+}
+
+/// Whether `[low, high]` may contain a point `(k + offset) multiple π` for some integer `k`.
+/// This rounds outwards, with π somewhere between `PI` and `PI_UP`, so it may report a point
+/// that is only just outside, but never misses one.
+fn may_contain_point(low: f64, high: f64, multiple: f64, offset: f64) -> bool {
+    let (period_low, period_high) = (PI.mul_down(&multiple), PI_UP.mul_up(&multiple));
+    let start = if low >= 0.0 {
+        low.div_down(&period_high)
+    } else {
+        low.div_down(&period_low)
+    };
+    let end = if high >= 0.0 {
+        high.div_up(&period_low)
+    } else {
+        high.div_up(&period_high)
+    };
+    start.sub_down(&offset).ceil() <= end.sub_up(&offset).floor()
+}
+
+fn unit_range() -> Bounds<f64> {
+    Bounds::Range(Some(Bound::inclusive(-1.0)), Some(Bound::inclusive(1.0)))
+}
+
+/// Finite candidates for an endpoint of a result, from the enclosure `f` gives at `bound`.
+fn candidates<F: Fn(f64) -> (f64, f64)>(bound: &Bound<f64>, f: F) -> [Option<Endpoint<f64>>; 2] {
+    rounded_candidates(bound.bound_type.clone(), f(bound.value))
+}
+
+/// Trigonometric functions for `f64`, which account for the periodicity of the functions. Each
+/// endpoint of the result is computed with the platform's math library and then rounded outwards,
+/// so the results contain every exact value.
+impl Bounds<f64> {
+    /// The sines of the values. If the bounds cross a peak or trough of the sine, its result
+    /// reaches 1 or -1, so `sin([0, 2])` is `[0, 1]`.
+    pub fn sin(&self) -> Bounds<f64> {
+        self.periodic(sin_enclosure, 0.25, 0.75)
+    }
+
+    /// The cosines of the values, like [`Bounds::sin`].
+    pub fn cos(&self) -> Bounds<f64> {
+        self.periodic(cos_enclosure, 0.0, 0.5)
+    }
+
+    /// Applies `f`, a function with period 2π that takes values in `[-1, 1]`. It has its maxima
+    /// at `(k + max) 2π`, its minima at `(k + min) 2π`, and is monotonic in between, so its
+    /// extremes over the bounds are at their endpoints, or at a maximum or minimum inside them.
+    fn periodic(&self, f: fn(f64) -> (f64, f64), max: f64, min: f64) -> Bounds<f64> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let (a, b) = match self {
+            Bounds::Exact(x) => {
+                let (low, high) = f(*x);
+                return enclose(low, high);
+            }
+            Bounds::Range(Some(a), Some(b)) => (a, b),
+            _ => return unit_range(),
+        };
+        let peak = may_contain_point(a.value, b.value, 2.0, max).then(|| Bound::inclusive(1.0));
+        let trough = may_contain_point(a.value, b.value, 2.0, min).then(|| Bound::inclusive(-1.0));
+        let extremes = [peak, trough].into_iter().flatten().map(Endpoint::Finite);
+        hull(
+            candidates(a, f)
+                .into_iter()
+                .chain(candidates(b, f))
+                .flatten()
+                .chain(extremes),
+        )
+    }
+
+    /// The tangents of the values. The tangent has a pole at every `π/2 + kπ`, where it jumps
+    /// from positive to negative infinity. If the bounds contain a pole, the result is split into
+    /// a piece without a lower bound and a piece without an upper bound, such as
+    /// `tan([1, 2]) = (-∞, tan 2] ∪ [tan 1, ∞)`. It is `(-∞, ∞)` if those pieces overlap.
+    pub fn tan(&self) -> Split<f64> {
+        if self.is_empty() {
+            return Split::Empty;
+        }
+        let all = Split::One(Bounds::Range(None, None));
+        let (a, b) = match self {
+            Bounds::Exact(x) => {
+                let (low, high) = tan_enclosure(*x);
+                return Split::One(enclose(low, high));
+            }
+            Bounds::Range(Some(a), Some(b)) => (a, b),
+            _ => return all,
+        };
+        // spanning a whole period takes every value
+        if b.value.sub_up(&a.value) >= PI {
+            return all;
+        }
+        let (a_low, a_high) = tan_enclosure(a.value);
+        let (b_low, b_high) = tan_enclosure(b.value);
+        // within less than a period, the tangent only decreases from a to b across a pole
+        let monotonic = a.value == b.value
+            || a_high < b_low
+            || (a_low <= b_high && !may_contain_point(a.value, b.value, 1.0, 0.5));
+        if monotonic {
+            return Split::One(Bounds::Range(
+                Some(Bound {
+                    bound_type: a.bound_type.clone(),
+                    value: a_low,
+                }),
+                Some(Bound {
+                    bound_type: b.bound_type.clone(),
+                    value: b_high,
+                }),
+            ));
+        }
+        if a_low <= b_high {
+            return all;
+        }
+        Split::Two(
+            Bounds::Range(
+                None,
+                Some(Bound {
+                    bound_type: b.bound_type.clone(),
+                    value: b_high,
+                }),
+            ),
+            Bounds::Range(
+                Some(Bound {
+                    bound_type: a.bound_type.clone(),
+                    value: a_low,
+                }),
+                None,
+            ),
+        )
+    }
+
+    /// The angles `atan2(y, x)` of the points with `y` in these bounds and `x` in `x`, in
+    /// `[-π, π]`. The angle jumps from π to -π across the negative x axis, so if the points
+    /// reach it, or the origin, the result is every angle.
+    pub fn atan2(&self, x: &Bounds<f64>) -> Bounds<f64> {
+        if self.is_empty() {
+            return self.clone();
+        }
+        if x.is_empty() {
+            return x.clone();
+        }
+        if let (Bounds::Exact(y), Bounds::Exact(x)) = (self, x) {
+            let (low, high) = atan2_enclosure(*y, *x);
+            return enclose(low, high);
+        }
+        let (y_signs, x_signs) = (SignBounds::from_bounds(self), SignBounds::from_bounds(x));
+        if y_signs.zero && (x_signs.below_zero || x_signs.zero) {
+            return Bounds::Range(
+                Some(Bound::inclusive(-PI_UP)),
+                Some(Bound::inclusive(PI_UP)),
+            );
+        }
+        // Away from the negative x axis the angle is continuous, and its extremes over the box
+        // are at the corners. At an unbounded corner, the angle approaches the direction of each
+        // axis the box extends along.
+        let directions = |value: f64| {
+            let enclosure = if value == 0.0 {
+                (value, value)
+            } else {
+                (value.next_down(), value.next_up())
+            };
+            rounded_candidates(BoundType::Exclusive, enclosure)
+        };
+        let axis = |endpoint: &Endpoint<&f64>, positive: f64, negative: f64| match endpoint {
+            Endpoint::NegInfinity => Some(negative),
+            Endpoint::Finite(_) => None,
+            Endpoint::PosInfinity => Some(positive),
+        };
+        let (ys, xs) = (
+            [self.lower_endpoint(), self.upper_endpoint()],
+            [x.lower_endpoint(), x.upper_endpoint()],
+        );
+        let mut corners = Vec::with_capacity(16);
+        for y in &ys {
+            for x in &xs {
+                if let (Endpoint::Finite(y), Endpoint::Finite(x)) = (y, x) {
+                    let bound_type = y.bound_type.combine(&x.bound_type);
+                    corners.extend(rounded_candidates(
+                        bound_type,
+                        atan2_enclosure(*y.value, *x.value),
+                    ));
+                    continue;
+                }
+                let y_sign = if y_signs.above_zero { 1.0 } else { -1.0 };
+                let y_axis = axis(y, FRAC_PI_2, -FRAC_PI_2);
+                let x_axis = axis(x, 0.0, PI.copysign(y_sign));
+                for direction in [y_axis, x_axis].into_iter().flatten() {
+                    corners.extend(directions(direction));
+                }
+            }
+        }
+        hull(corners.into_iter().flatten())
+    }
+}